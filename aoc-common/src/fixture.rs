@@ -0,0 +1,22 @@
+// Puzzle examples for tests, checked in as dayN/fixtures/*.txt.
+
+// E.g. `aoc_common::fixture!("example.txt")`, from dayN's tests.
+#[macro_export]
+macro_rules! fixture {
+   ($name:expr) => {
+      include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/", $name))
+   };
+}
+
+// One example per line, with its expected answer after the last space:
+//
+//    8A004A801A8002F478 16
+pub fn cases(fixture: &str) -> Vec<(&str, &str)> {
+   fixture.lines()
+      .filter(|line| !line.trim().is_empty())
+      .map(|line| {
+         line.rsplit_once(' ').unwrap_or_else(|| {
+            panic!("Expected \"<example> <answer>\", got {:?}", line)
+         })
+      }).collect()
+}
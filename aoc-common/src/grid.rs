@@ -0,0 +1,361 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops;
+
+use crate::linalg::Vector;
+use crate::parse::{Result, Source};
+
+// -
+
+#[derive(PartialEq,Eq,Hash,Clone,Copy,Debug,Default,PartialOrd,Ord)]
+pub struct Point {
+   pub x: isize,
+   pub y: isize,
+}
+impl Point {
+   pub const fn new(x: isize, y: isize) -> Point {
+      Point{x, y}
+   }
+   pub fn offset(mut self, rhs: (isize,isize)) -> Self {
+      self.x += rhs.0;
+      self.y += rhs.1;
+      self
+   }
+   // Unchecked: may step off the edge of any grid.
+   pub fn nearest4(&self) -> [Point; 4] {
+      [
+         self.offset((-1,  0)),
+         self.offset(( 1,  0)),
+         self.offset(( 0, -1)),
+         self.offset(( 0,  1)),
+      ]
+   }
+   pub fn nearest8(&self) -> [Point; 8] {
+      [
+         self.offset((-1, -1)),
+         self.offset(( 0, -1)),
+         self.offset(( 1, -1)),
+         self.offset((-1,  0)),
+       //self.offset(( 0,  0)),
+         self.offset(( 1,  0)),
+         self.offset((-1,  1)),
+         self.offset(( 0,  1)),
+         self.offset(( 1,  1)),
+      ]
+   }
+}
+
+// Point stays its own type rather than a Vector<2>: grids are indexed by
+// it, in isize, and most days want `p.x` and `p.y` more than vector math.
+// Where they want both, it's a conversion away.
+impl From<Vector<2>> for Point {
+   fn from(v: Vector<2>) -> Point {
+      Point{x: v[0] as isize, y: v[1] as isize}
+   }
+}
+impl From<Point> for Vector<2> {
+   fn from(p: Point) -> Vector<2> {
+      Vector::new([p.x as i64, p.y as i64])
+   }
+}
+impl ops::Add<Vector<2>> for Point {
+   type Output = Point;
+   fn add(self, rhs: Vector<2>) -> Point {
+      let rhs = Point::from(rhs);
+      self.offset((rhs.x, rhs.y))
+   }
+}
+
+// -
+
+#[derive(Clone,PartialEq,Eq,Debug,Default)]
+pub struct Grid<T> {
+   pub rows: Vec<Vec<T>>,
+}
+impl<T: Clone> Grid<T> {
+   pub fn new() -> Grid<T> {
+      Grid::<T>{rows: Vec::new()}
+   }
+   pub fn from_elem(size: Point, elem: T) -> Grid<T> {
+      Grid{rows: vec![vec![elem; size.x as usize]; size.y as usize]}
+   }
+
+   pub fn len(&self) -> Point {
+      if self.rows.is_empty() {
+         return Point{x:0,y:0};
+      }
+      Point{x: self.rows[0].len() as isize,
+            y: self.rows.len() as isize}
+   }
+   pub fn is_empty(&self) -> bool {
+      let size = self.len();
+      size.x == 0 || size.y == 0
+   }
+   pub fn is_valid(&self, p: &Point) -> bool {
+      let size = self.len();
+      0 <= p.x && p.x < size.x &&
+      0 <= p.y && p.y < size.y
+   }
+
+   // -
+   // Access
+
+   pub fn at(&self, p: &Point) -> &T {
+      &self.rows[p.y as usize][p.x as usize]
+   }
+   pub fn at_mut(&mut self, p: &Point) -> &mut T {
+      &mut self.rows[p.y as usize][p.x as usize]
+   }
+   pub fn get(&self, p: &Point) -> Option<&T> {
+      if !self.is_valid(p) { return None; }
+      Some(self.at(p))
+   }
+   pub fn get_mut(&mut self, p: &Point) -> Option<&mut T> {
+      if !self.is_valid(p) { return None; }
+      Some(self.at_mut(p))
+   }
+
+   // Off the right edge comes back in on the left, etc.
+   pub fn wrap(&self, p: &Point) -> Point {
+      assert!(!self.is_empty(), "Can't wrap onto an empty grid.");
+      let size = self.len();
+      Point{x: p.x.rem_euclid(size.x),
+            y: p.y.rem_euclid(size.y)}
+   }
+   pub fn at_wrapped(&self, p: &Point) -> &T {
+      self.at(&self.wrap(p))
+   }
+   pub fn at_wrapped_mut(&mut self, p: &Point) -> &mut T {
+      let p = self.wrap(p);
+      self.at_mut(&p)
+   }
+
+   pub fn fill(&mut self, elem: T) {
+      self.rows.iter_mut().for_each(|row| {
+         row.iter_mut().for_each(|v| *v = elem.clone());
+      });
+   }
+
+   // -
+   // Iteration
+
+   // Row-major, top-left first.
+   pub fn points(&self) -> impl Iterator<Item=Point> {
+      let size = self.len();
+      (0..size.y).flat_map(move |y| {
+         (0..size.x).map(move |x| Point{x, y})
+      })
+   }
+   pub fn iter(&self) -> impl Iterator<Item=(Point,&T)> {
+      self.points().map(move |p| (p, self.at(&p)))
+   }
+   pub fn nearest4<'a>(&'a self, p: &Point) -> impl Iterator<Item=Point> + 'a {
+      IntoIterator::into_iter(p.nearest4()).filter(move |n| self.is_valid(n))
+   }
+   pub fn nearest8<'a>(&'a self, p: &Point) -> impl Iterator<Item=Point> + 'a {
+      IntoIterator::into_iter(p.nearest8()).filter(move |n| self.is_valid(n))
+   }
+   pub fn nearest4_wrapped<'a>(&'a self, p: &Point) -> impl Iterator<Item=Point> + 'a {
+      IntoIterator::into_iter(p.nearest4()).map(move |n| self.wrap(&n))
+   }
+   pub fn nearest8_wrapped<'a>(&'a self, p: &Point) -> impl Iterator<Item=Point> + 'a {
+      IntoIterator::into_iter(p.nearest8()).map(move |n| self.wrap(&n))
+   }
+
+   // -
+   // Resizing
+
+   pub fn insert_row(&mut self, index: usize, elems: Vec<T>) {
+      if !self.rows.is_empty() {
+         assert_eq!(elems.len(), self.rows[0].len());
+      }
+      self.rows.insert(index, elems);
+   }
+   pub fn insert_col(&mut self, index: usize, elems: Vec<T>) {
+      assert_eq!(elems.len(), self.rows.len());
+      for (row, elem) in self.rows.iter_mut().zip(elems) {
+         row.insert(index, elem);
+      }
+   }
+   pub fn push_row(&mut self, elems: Vec<T>) {
+      self.insert_row(self.len().y as usize, elems);
+   }
+   pub fn push_col(&mut self, elems: Vec<T>) {
+      self.insert_col(self.len().x as usize, elems);
+   }
+
+   // Pads each edge with one more row or column of `elem`.
+   pub fn insert_border(&mut self, elem: T) {
+      let size = self.len();
+      let mut border = Vec::with_capacity(2+std::cmp::max(size.x, size.y) as usize);
+      // Col first
+      border.resize(size.y as usize, elem.clone());
+      self.insert_col(0, border.clone());
+      self.push_col(border.clone());
+
+      border.resize(2+size.x as usize, elem.clone());
+      self.insert_row(0, border.clone());
+      self.push_row(border);
+   }
+   // Overwrites the outermost rows and columns with `elem`.
+   pub fn set_border(&mut self, elem: T) {
+      let size = self.len();
+      self.rows[0].fill(elem.clone());
+      for row in self.rows.iter_mut() {
+         row[0] = elem.clone();
+         row[size.x as usize - 1] = elem.clone();
+      }
+      self.rows[size.y as usize - 1].fill(elem);
+   }
+
+   // -
+   // Parsing
+
+   // E.g. "2199943210\n3987894921\n"
+   pub fn from_digits(input: &str) -> Result<Grid<T>> where T: From<u8> {
+      Grid::from_char_map(input, "a digit", |c| {
+         c.to_digit(10).map(|digit| T::from(digit as u8))
+      })
+   }
+   // E.g. "v...>>.vv>\n.vv>>.vv..\n"
+   // `f` returns None for chars that aren't `expected`.
+   // Never empty, so `wrap` always has somewhere to wrap to.
+   pub fn from_char_map<F>(input: &str, expected: &str, mut f: F)
+         -> Result<Grid<T>> where F: FnMut(char) -> Option<T> {
+      let src = Source::new(input);
+      src.not_empty(expected)?;
+      let mut width = None;
+      input.trim().lines().map(|line| {
+         let row = line.char_indices().map(|(i,c)| {
+            f(c).ok_or_else(|| {
+               src.error(&line[i..i+c.len_utf8()], expected)
+            })
+         }).collect::<Result<Vec<_>>>()?;
+         let width = *width.get_or_insert(row.len());
+         if row.len() != width {
+            return Err(src.error(&line[line.len()..],
+                                 &format!("{} columns", width)));
+         }
+         Ok(row)
+      }).collect()
+   }
+}
+
+impl<T: Clone> FromIterator<Vec<T>> for Grid<T> {
+   fn from_iter<I: IntoIterator<Item=Vec<T>>>(iter: I) -> Self {
+      let mut ret = Grid::new();
+      iter.into_iter().for_each(|row| ret.push_row(row));
+      ret
+   }
+}
+
+// One line per row, no separators, so a Grid<char> prints like the puzzle.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      for row in self.rows.iter() {
+         for v in row.iter() {
+            write!(f, "{}", v)?;
+         }
+         writeln!(f)?;
+      }
+      Ok(())
+   }
+}
+
+// -
+
+#[test]
+fn test_access() {
+   let mut grid = Grid::<u8>::from_digits("\
+123
+456
+").unwrap();
+   assert_eq!(grid.len(), Point::new(3,2));
+   assert_eq!(*grid.at(&Point::new(2,1)), 6);
+   assert_eq!(grid.get(&Point::new(3,1)), None);
+   assert_eq!(grid.get(&Point::new(-1,0)), None);
+   assert_eq!(grid.get(&Point::new(0,1)), Some(&4));
+   assert_eq!(Point::new(2,1) + Vector::new([-2,1]), Point::new(0,2));
+   assert_eq!(Point::from(Vector::new([-2,1])), Point::new(-2,1));
+   assert_eq!(Vector::from(Point::new(-2,1)), Vector::new([-2,1]));
+
+   assert_eq!(*grid.at_wrapped(&Point::new(3,2)), 1);
+   assert_eq!(*grid.at_wrapped(&Point::new(-1,-1)), 6);
+   *grid.at_wrapped_mut(&Point::new(-3,5)) = 0;
+   assert_eq!(grid.to_string(), "123\n056\n");
+
+   let e = Grid::<u8>::from_digits("12\n3x\n").unwrap_err();
+   assert_eq!((e.line, e.col), (2,2));
+   let e = Grid::<u8>::from_digits("12\n3\n").unwrap_err();
+   assert_eq!(e.expected, "2 columns");
+   let e = Grid::<u8>::from_digits("\n").unwrap_err();
+   assert_eq!((e.expected.as_str(), e.found.as_str()),
+              ("a digit", "end of input"));
+
+   assert!(Grid::<u8>::new().is_empty());
+   assert_eq!(Grid::<u8>::new().get(&Point::new(0,0)), None);
+}
+
+#[test]
+fn test_neighbors() {
+   let grid = Grid::from_elem(Point::new(3,3), 0u8);
+
+   let corner: Vec<_> = grid.nearest4(&Point::new(0,0)).collect();
+   assert_eq!(corner, [Point::new(1,0), Point::new(0,1)]);
+   assert_eq!(grid.nearest4(&Point::new(1,1)).count(), 4);
+   assert_eq!(grid.nearest8(&Point::new(0,0)).count(), 3);
+   assert_eq!(grid.nearest8(&Point::new(1,1)).count(), 8);
+   assert_eq!(grid.nearest8(&Point::new(2,1)).count(), 5);
+
+   let wrapped: Vec<_> = grid.nearest4_wrapped(&Point::new(0,0)).collect();
+   assert_eq!(wrapped, [Point::new(2,0), Point::new(1,0),
+                        Point::new(0,2), Point::new(0,1)]);
+   assert_eq!(grid.nearest8_wrapped(&Point::new(0,0)).count(), 8);
+}
+
+#[test]
+fn test_border() {
+   let mut grid = Grid::<char>::from_char_map("ab\ncd\n", "a letter", Some)
+      .unwrap();
+   grid.insert_border('.');
+   assert_eq!(grid.to_string(), "\
+....
+.ab.
+.cd.
+....
+");
+   grid.fill('x');
+   grid.set_border('#');
+   assert_eq!(grid.to_string(), "\
+####
+#xx#
+#xx#
+####
+");
+}
+
+#[test]
+fn test_insert() {
+   let mut grid: Grid<u8> = vec![vec![1,2]].into_iter().collect();
+   grid.push_row(vec![3,4]);
+   grid.push_col(vec![5,6]);
+   grid.insert_row(0, vec![7,8,9]);
+   grid.insert_col(1, vec![0,0,0]);
+   assert_eq!(grid.to_string(), "\
+7089
+1025
+3046
+");
+   let points: Vec<_> = grid.iter()
+      .filter(|(_,v)| **v == 0)
+      .map(|(p,_)| p)
+      .collect();
+   assert_eq!(points, [Point::new(1,0), Point::new(1,1), Point::new(1,2)]);
+}
+
+#[test]
+#[should_panic]
+fn test_insert_mismatched() {
+   let mut grid = Grid::from_elem(Point::new(2,2), 0u8);
+   grid.push_row(vec![1]);
+}
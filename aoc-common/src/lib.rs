@@ -0,0 +1,14 @@
+// Shared helpers for the daily solutions.
+
+pub mod fixture;
+pub mod grid;
+pub mod linalg;
+pub mod parse;
+pub mod rng;
+pub mod solution;
+
+pub use grid::{Grid, Point};
+pub use linalg::{Matrix, Vector};
+pub use parse::{ParseError, Result, Source};
+pub use rng::Rng;
+pub use solution::Solution;
@@ -0,0 +1,392 @@
+// Integer vectors and matrices of any size, for points, offsets and the
+// transforms between them. Transforms are homogeneous: the last axis is 1
+// for points and 0 for offsets, so a Matrix<4> can turn and move 3D points.
+
+use std::fmt;
+use std::ops;
+
+// -
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug,PartialOrd,Ord)]
+pub struct Vector<const N: usize> {
+   pub data: [i64; N],
+}
+impl<const N: usize> Vector<N> {
+   pub const fn new(data: [i64; N]) -> Vector<N> {
+      Vector{data}
+   }
+   pub const fn zero() -> Vector<N> {
+      Vector{data: [0; N]}
+   }
+   // The homogeneous origin: all 0, but the last axis is 1.
+   pub const fn origin() -> Vector<N> {
+      let mut ret = Vector::zero();
+      ret.data[N - 1] = 1;
+      ret
+   }
+   pub const fn axis(i: usize) -> Vector<N> {
+      let mut ret = Vector::zero();
+      ret.data[i] = 1;
+      ret
+   }
+
+   pub fn map(self, f: impl Fn(i64) -> i64) -> Vector<N> {
+      Vector{data: self.data.map(f)}
+   }
+   pub fn zip_map(self, rhs: Vector<N>, f: impl Fn(i64, i64) -> i64)
+         -> Vector<N> {
+      let mut ret = self;
+      for i in 0..N {
+         ret.data[i] = f(self.data[i], rhs.data[i]);
+      }
+      ret
+   }
+
+   pub fn dot(&self, rhs: &Vector<N>) -> i64 {
+      (0..N).map(|i| self.data[i] * rhs.data[i]).sum()
+   }
+   pub fn manhattan(&self) -> i64 {
+      self.data.iter().map(|x| x.abs()).sum()
+   }
+   pub fn euclidean(&self) -> f64 {
+      (self.dot(self) as f64).sqrt()
+   }
+}
+
+impl<const N: usize> ops::Index<usize> for Vector<N> {
+   type Output = i64;
+   fn index(&self, i: usize) -> &i64 {
+      &self.data[i]
+   }
+}
+impl<const N: usize> ops::IndexMut<usize> for Vector<N> {
+   fn index_mut(&mut self, i: usize) -> &mut i64 {
+      &mut self.data[i]
+   }
+}
+
+impl<const N: usize> ops::Add for Vector<N> {
+   type Output = Vector<N>;
+   fn add(self, rhs: Vector<N>) -> Vector<N> {
+      self.zip_map(rhs, |a, b| a + b)
+   }
+}
+impl<const N: usize> ops::Sub for Vector<N> {
+   type Output = Vector<N>;
+   fn sub(self, rhs: Vector<N>) -> Vector<N> {
+      self.zip_map(rhs, |a, b| a - b)
+   }
+}
+impl<const N: usize> ops::Neg for Vector<N> {
+   type Output = Vector<N>;
+   fn neg(self) -> Vector<N> {
+      self.map(|a| -a)
+   }
+}
+impl<const N: usize> ops::Mul<i64> for Vector<N> {
+   type Output = Vector<N>;
+   fn mul(self, rhs: i64) -> Vector<N> {
+      self.map(|a| a * rhs)
+   }
+}
+impl<const N: usize> ops::AddAssign for Vector<N> {
+   fn add_assign(&mut self, rhs: Vector<N>) {
+      *self = *self + rhs;
+   }
+}
+impl<const N: usize> ops::SubAssign for Vector<N> {
+   fn sub_assign(&mut self, rhs: Vector<N>) {
+      *self = *self - rhs;
+   }
+}
+
+// "(1, 2, 3)"
+impl<const N: usize> fmt::Display for Vector<N> {
+   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      write!(f, "(")?;
+      for (i, x) in self.data.iter().enumerate() {
+         if i != 0 {
+            write!(f, ", ")?;
+         }
+         write!(f, "{}", x)?;
+      }
+      write!(f, ")")
+   }
+}
+
+// -
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub struct Matrix<const N: usize> {
+   pub rows: [Vector<N>; N],
+}
+impl<const N: usize> Matrix<N> {
+   pub const fn new(rows: [Vector<N>; N]) -> Matrix<N> {
+      Matrix{rows}
+   }
+   pub const fn identity() -> Matrix<N> {
+      let mut rows = [Vector::zero(); N];
+      let mut i = 0;
+      while i < N {
+         rows[i].data[i] = 1;
+         i += 1;
+      }
+      Matrix{rows}
+   }
+   // Moves points by `v`. (Its last axis is ignored.)
+   pub fn translation(v: &Vector<N>) -> Matrix<N> {
+      let mut ret = Matrix::identity();
+      for i in 0..N - 1 {
+         ret.rows[i].data[N - 1] = v.data[i];
+      }
+      ret
+   }
+
+   pub fn col(&self, c: usize) -> Vector<N> {
+      Vector{data: self.rows.map(|row| row.data[c])}
+   }
+   pub fn transpose(&self) -> Matrix<N> {
+      let mut ret = *self;
+      for c in 0..N {
+         ret.rows[c] = self.col(c);
+      }
+      ret
+   }
+   // Where the origin goes.
+   pub fn position(&self) -> Vector<N> {
+      *self * Vector::origin()
+   }
+
+   // The inverse of a rotation (or reflection) followed by a translation,
+   // which is just turning back, then moving back.
+   pub fn rigid_inverse(&self) -> Matrix<N> {
+      debug_assert_eq!(self.rows[N - 1], Vector::origin());
+      let mut turn = *self;
+      turn.rows[N - 1] = Vector::origin();
+      for i in 0..N - 1 {
+         turn.rows[i].data[N - 1] = 0;
+      }
+      let unturn = turn.transpose();
+      let back = -(unturn * self.col(N - 1));
+      Matrix::translation(&back) * unturn
+   }
+
+   // Every way to swap and flip the first `axes` axes (leaving any others
+   // be): 8 for a square, 48 for a cube.
+   pub fn symmetries(axes: usize) -> Vec<Matrix<N>> {
+      assert!(axes <= N);
+      let mut ret = Vec::new();
+      let mut perm: Vec<usize> = (0..axes).collect();
+      loop {
+         for signs in 0..1 << axes {
+            let mut m = Matrix::identity();
+            for (r, &c) in perm.iter().enumerate() {
+               let sign = if signs >> r & 1 == 1 { -1 } else { 1 };
+               m.rows[r] = Vector::axis(c) * sign;
+            }
+            ret.push(m);
+         }
+         if !next_permutation(&mut perm) {
+            return ret;
+         }
+      }
+   }
+   // Just the symmetries that are rotations, not mirror images: 4 for a
+   // square, 24 for a cube. The identity is first.
+   pub fn rotations(axes: usize) -> Vec<Matrix<N>> {
+      Matrix::symmetries(axes).into_iter()
+         .filter(|m| m.signed_permutation_det() == 1)
+         .collect()
+   }
+   // Only right for symmetries, which have one ±1 per row and column.
+   fn signed_permutation_det(&self) -> i64 {
+      let mut det = 1;
+      let perm: Vec<usize> = self.rows.iter().map(|row| {
+         let c = row.data.iter().position(|&x| x != 0).unwrap();
+         det *= row.data[c];
+         c
+      }).collect();
+      for i in 0..N {
+         for j in i+1..N {
+            if perm[i] > perm[j] {
+               det = -det;
+            }
+         }
+      }
+      det
+   }
+}
+
+// Steps to the next permutation in lexicographic order, or returns false
+// after the last.
+fn next_permutation(v: &mut [usize]) -> bool {
+   let i = match (1..v.len()).rev().find(|&i| v[i - 1] < v[i]) {
+      Some(i) => i,
+      None => return false,
+   };
+   let j = (i..v.len()).rev().find(|&j| v[j] > v[i - 1]).unwrap();
+   v.swap(i - 1, j);
+   v[i..].reverse();
+   true
+}
+
+impl<const N: usize> ops::Index<usize> for Matrix<N> {
+   type Output = Vector<N>;
+   fn index(&self, r: usize) -> &Vector<N> {
+      &self.rows[r]
+   }
+}
+impl<const N: usize> ops::IndexMut<usize> for Matrix<N> {
+   fn index_mut(&mut self, r: usize) -> &mut Vector<N> {
+      &mut self.rows[r]
+   }
+}
+
+impl<const N: usize> ops::Add for Matrix<N> {
+   type Output = Matrix<N>;
+   fn add(mut self, rhs: Matrix<N>) -> Matrix<N> {
+      for r in 0..N {
+         self.rows[r] += rhs.rows[r];
+      }
+      self
+   }
+}
+impl<const N: usize> ops::Sub for Matrix<N> {
+   type Output = Matrix<N>;
+   fn sub(mut self, rhs: Matrix<N>) -> Matrix<N> {
+      for r in 0..N {
+         self.rows[r] -= rhs.rows[r];
+      }
+      self
+   }
+}
+impl<const N: usize> ops::Neg for Matrix<N> {
+   type Output = Matrix<N>;
+   fn neg(self) -> Matrix<N> {
+      Matrix{rows: self.rows.map(|row| -row)}
+   }
+}
+impl<const N: usize> ops::Mul<Vector<N>> for Matrix<N> {
+   type Output = Vector<N>;
+   fn mul(self, rhs: Vector<N>) -> Vector<N> {
+      Vector{data: self.rows.map(|row| row.dot(&rhs))}
+   }
+}
+// Composition: `a * b` is `b`, then `a`.
+impl<const N: usize> ops::Mul for Matrix<N> {
+   type Output = Matrix<N>;
+   fn mul(self, rhs: Matrix<N>) -> Matrix<N> {
+      let rhst = rhs.transpose();
+      Matrix{rows: self.rows.map(|row| rhst * row)}
+   }
+}
+
+// One row per line, with the columns lined up:
+// "( 0, 1)\n(-1, 0)"
+impl<const N: usize> fmt::Display for Matrix<N> {
+   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      let width = self.rows.iter().flat_map(|row| row.data.iter())
+         .map(|x| x.to_string().len()).max().unwrap_or(0);
+      for (r, row) in self.rows.iter().enumerate() {
+         if r != 0 {
+            writeln!(f)?;
+         }
+         write!(f, "(")?;
+         for (c, x) in row.data.iter().enumerate() {
+            if c != 0 {
+               write!(f, ", ")?;
+            }
+            write!(f, "{:>1$}", x, width)?;
+         }
+         write!(f, ")")?;
+      }
+      Ok(())
+   }
+}
+
+// -
+
+#[test]
+fn test_vector() {
+   let a = Vector::new([1, -2, 3]);
+   let b = Vector::new([4, 5, -6]);
+   assert_eq!(a + b, Vector::new([5, 3, -3]));
+   assert_eq!(a - b, Vector::new([-3, -7, 9]));
+   assert_eq!(-a, Vector::new([-1, 2, -3]));
+   assert_eq!(a * 2, Vector::new([2, -4, 6]));
+   assert_eq!(a.dot(&b), 4 - 10 - 18);
+   assert_eq!(a.manhattan(), 6);
+   assert_eq!(Vector::new([3, -4]).euclidean(), 5.0);
+   assert_eq!(a.zip_map(b, i64::max), Vector::new([4, 5, 3]));
+   assert_eq!(Vector::<4>::origin(), Vector::new([0, 0, 0, 1]));
+
+   let mut c = a;
+   c += b;
+   c[2] = 7;
+   assert_eq!((c, c[0]), (Vector::new([5, 3, 7]), 5));
+   assert_eq!(a.to_string(), "(1, -2, 3)");
+}
+
+#[test]
+fn test_matrix() {
+   let i = Matrix::<3>::identity();
+   let m = Matrix::new([Vector::new([1, 2, 3]),
+                        Vector::new([4, 5, 6]),
+                        Vector::new([7, 8, 10])]);
+   assert_eq!(m * i, m);
+   assert_eq!(i * m, m);
+   assert_eq!(m + m, m * Matrix::new([Vector::axis(0) * 2,
+                                      Vector::axis(1) * 2,
+                                      Vector::axis(2) * 2]));
+   assert_eq!(m - m, -(m - m));
+   assert_eq!(m.transpose()[0], Vector::new([1, 4, 7]));
+   assert_eq!(m * Vector::new([1, 0, -1]), Vector::new([-2, -2, -3]));
+   assert_eq!(m.to_string(), "( 1,  2,  3)\n( 4,  5,  6)\n( 7,  8, 10)");
+
+   // Turn a quarter about z, then move.
+   let turn = Matrix::new([Vector::new([0, -1, 0, 0]),
+                           Vector::new([1,  0, 0, 0]),
+                           Vector::new([0,  0, 1, 0]),
+                           Vector::origin()]);
+   let pose = Matrix::translation(&Vector::new([10, 20, 30, 0])) * turn;
+   let p = Vector::new([1, 2, 3, 1]);
+   assert_eq!(pose * p, Vector::new([8, 21, 33, 1]));
+   assert_eq!(pose.position(), Vector::new([10, 20, 30, 1]));
+   assert_eq!(pose.rigid_inverse() * (pose * p), p);
+   assert_eq!(pose * pose.rigid_inverse(), Matrix::identity());
+   // Offsets don't move.
+   assert_eq!(pose * Vector::new([1, 2, 3, 0]), Vector::new([-2, 1, 3, 0]));
+}
+
+#[test]
+fn test_rotations() {
+   assert_eq!(Matrix::<2>::symmetries(2).len(), 8);
+   assert_eq!(Matrix::<2>::rotations(2).len(), 4);
+   assert_eq!(Matrix::<3>::symmetries(3).len(), 48);
+   assert_eq!(Matrix::<4>::symmetries(4).len(), 384);
+
+   let rots = Matrix::<4>::rotations(3);
+   assert_eq!(rots.len(), 24);
+   assert_eq!(rots[0], Matrix::identity());
+   let mut facings: Vec<(Vector<4>, Vector<4>)> = rots.iter().map(|m| {
+      // The homogeneous axis stays put.
+      assert_eq!(m[3], Vector::origin());
+      assert_eq!(*m * m.transpose(), Matrix::identity());
+      (*m * Vector::axis(0), *m * Vector::axis(2))
+   }).collect();
+   // Each faces one of 6 ways, with one of 4 ways up.
+   facings.sort_unstable();
+   facings.dedup();
+   assert_eq!(facings.len(), 24);
+
+   // Quarter turns of a square.
+   let quarter = Matrix::new([Vector::new([0, -1]), Vector::new([1, 0])]);
+   let mut turns = vec![Matrix::identity()];
+   for _ in 0..3 {
+      turns.push(quarter * *turns.last().unwrap());
+   }
+   turns.sort_unstable_by_key(|m| m.rows);
+   let mut rots = Matrix::<2>::rotations(2);
+   rots.sort_unstable_by_key(|m| m.rows);
+   assert_eq!(rots, turns);
+}
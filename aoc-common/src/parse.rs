@@ -0,0 +1,175 @@
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+// -
+
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub struct ParseError {
+   // Filled in by Solution::load, since parsers don't know what day it is.
+   pub day: Option<u32>,
+   // Both 1-based.
+   pub line: usize,
+   pub col: usize,
+   pub expected: String,
+   pub found: String,
+}
+impl ParseError {
+   pub fn on_day(mut self, day: u32) -> ParseError {
+      self.day = Some(day);
+      self
+   }
+}
+impl fmt::Display for ParseError {
+   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      if let Some(day) = self.day {
+         write!(f, "day{}: ", day)?;
+      }
+      write!(f, "line {}, column {}: expected {}, found {}",
+             self.line, self.col, self.expected, self.found)
+   }
+}
+impl error::Error for ParseError {}
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+// -
+
+// The whole puzzle input, so that errors about any slice of it can say
+// where that slice was.
+#[derive(Clone,Copy)]
+pub struct Source<'a> {
+   pub text: &'a str,
+}
+impl<'a> Source<'a> {
+   pub fn new(text: &'a str) -> Source<'a> {
+      Source{text}
+   }
+
+   // Byte offset of `at` within the input.
+   // Slices from elsewhere are treated as the end of the input.
+   fn offset_of(&self, at: &str) -> usize {
+      let begin = self.text.as_ptr() as usize;
+      let at = at.as_ptr() as usize;
+      if at < begin || at > begin + self.text.len() {
+         return self.text.len();
+      }
+      at - begin
+   }
+
+   // Where anything missing from the end should have been.
+   pub fn end(&self) -> &'a str {
+      &self.text[self.text.len()..]
+   }
+
+   // (line, column), both 1-based.
+   pub fn locate(&self, at: &str) -> (usize, usize) {
+      let before = &self.text[..self.offset_of(at)];
+      let line = before.matches('\n').count() + 1;
+      let line_start = before.rfind('\n').map(|i| i+1).unwrap_or(0);
+      let col = before[line_start..].chars().count() + 1;
+      (line, col)
+   }
+
+   pub fn error(&self, at: &str, expected: &str) -> ParseError {
+      let (line, col) = self.locate(at);
+      let rest = &self.text[self.offset_of(at)..];
+      let mut found = at.lines().next().unwrap_or("");
+      if !rest.starts_with(found) {
+         found = ""; // `at` isn't from the input.
+      }
+      let found = if !found.is_empty() {
+         format!("{:?}", found)
+      } else if rest.is_empty() {
+         "end of input".to_string()
+      } else {
+         "end of line".to_string()
+      };
+      ParseError{
+         day: None,
+         line,
+         col,
+         expected: expected.to_string(),
+         found,
+      }
+   }
+
+   // -
+
+   pub fn split_once<'s>(&self, s: &'s str, pat: &str)
+         -> Result<(&'s str, &'s str)> {
+      s.split_once(pat).ok_or_else(|| {
+         self.error(s, &format!("{:?}", pat))
+      })
+   }
+
+   pub fn strip_prefix<'s>(&self, s: &'s str, prefix: &str)
+         -> Result<&'s str> {
+      s.strip_prefix(prefix).ok_or_else(|| {
+         self.error(s, &format!("{:?}", prefix))
+      })
+   }
+
+   // Input that's all whitespace has nothing to solve; `expected` names the
+   // first thing missing.
+   pub fn not_empty(&self, expected: &str) -> Result<()> {
+      if self.text.trim().is_empty() {
+         return Err(self.error(self.end(), expected));
+      }
+      Ok(())
+   }
+
+   // `expected` names what `s` should have been, e.g. "an integer".
+   pub fn parse<T: FromStr>(&self, s: &str, expected: &str) -> Result<T> {
+      s.parse().map_err(|_| self.error(s, expected))
+   }
+
+   // The first char of `s`.
+   pub fn digit(&self, s: &str, radix: u32) -> Result<u32> {
+      let c = s.chars().next();
+      let at = &s[..c.map(char::len_utf8).unwrap_or(0)];
+      c.and_then(|c| c.to_digit(radix)).ok_or_else(|| {
+         self.error(at, &format!("a base-{} digit", radix))
+      })
+   }
+}
+
+// -
+
+#[test]
+fn test_error() {
+   let input = "\
+1,2
+3;4
+5,x
+";
+   let src = Source::new(input);
+   let lines: Vec<_> = input.lines().collect();
+
+   assert_eq!(src.locate(lines[0]), (1,1));
+   assert_eq!(src.locate(&lines[2][2..]), (3,3));
+   assert_eq!(src.locate(&input[input.len()..]), (4,1));
+
+   assert_eq!(src.split_once(lines[0], ","), Ok(("1", "2")));
+   let e = src.split_once(lines[1], ",").unwrap_err();
+   assert_eq!(e.to_string(),
+              "line 2, column 1: expected \",\", found \"3;4\"");
+
+   let (_, y) = src.split_once(lines[2], ",").unwrap();
+   let e = src.parse::<i64>(y, "an integer").unwrap_err();
+   assert_eq!(e.on_day(7).to_string(),
+              "day7: line 3, column 3: expected an integer, found \"x\"");
+
+   let e = src.strip_prefix(&lines[0][3..], ",").unwrap_err();
+   assert_eq!((e.line, e.col), (1,4));
+   assert_eq!(e.found, "end of line");
+   let e = src.digit(src.end(), 10).unwrap_err();
+   assert_eq!(e.found, "end of input");
+   assert_eq!(src.not_empty("a number"), Ok(()));
+   let e = Source::new("\n").not_empty("a number").unwrap_err();
+   assert_eq!(e.to_string(),
+              "line 2, column 1: expected a number, found end of input");
+   let e = src.digit(&lines[2][2..], 16).unwrap_err();
+   assert_eq!(e.to_string(),
+              "line 3, column 3: expected a base-16 digit, found \"x\"");
+}
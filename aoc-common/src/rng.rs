@@ -0,0 +1,89 @@
+// A small seeded PRNG for the input generators, so a failing case can be
+// reproduced from its seed alone. (splitmix64: not for anything secret.)
+
+use std::ops::RangeInclusive;
+
+// -
+
+#[derive(Clone,Debug)]
+pub struct Rng {
+   state: u64,
+}
+impl Rng {
+   pub fn new(seed: u64) -> Rng {
+      Rng{state: seed}
+   }
+
+   pub fn next_u64(&mut self) -> u64 {
+      self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+      let mut z = self.state;
+      z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+      z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+      z ^ (z >> 31)
+   }
+
+   // In 0..n. (Slightly biased for huge `n`, which is fine here.)
+   pub fn below(&mut self, n: u64) -> u64 {
+      assert!(n > 0);
+      self.next_u64() % n
+   }
+
+   pub fn range(&mut self, r: RangeInclusive<i64>) -> i64 {
+      let (lo, hi) = r.into_inner();
+      assert!(lo <= hi);
+      // All of i64 is one more than a u64 can count, and every u64 fits.
+      let span = hi.wrapping_sub(lo) as u64;
+      let offset = match span.checked_add(1) {
+         Some(count) => self.below(count),
+         None => self.next_u64(),
+      };
+      lo.wrapping_add(offset as i64)
+   }
+
+   // True one time in `n`.
+   pub fn one_in(&mut self, n: u64) -> bool {
+      self.below(n) == 0
+   }
+
+   pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+      &items[self.below(items.len() as u64) as usize]
+   }
+
+   pub fn shuffle<T>(&mut self, items: &mut [T]) {
+      for i in (1..items.len()).rev() {
+         let j = self.below(i as u64 + 1) as usize;
+         items.swap(i, j);
+      }
+   }
+}
+
+// -
+
+#[test]
+fn test_rng() {
+   let mut a = Rng::new(7);
+   let mut b = Rng::new(7);
+   let xs: Vec<u64> = (0..10).map(|_| a.next_u64()).collect();
+   let ys: Vec<u64> = (0..10).map(|_| b.next_u64()).collect();
+   assert_eq!(xs, ys);
+   assert_ne!(Rng::new(8).next_u64(), xs[0]);
+
+   let mut seen = [false; 5];
+   for _ in 0..1000 {
+      let x = a.range(-2..=2);
+      seen[(x + 2) as usize] = true;
+   }
+   assert_eq!(seen, [true; 5]);
+   for _ in 0..100 {
+      a.range(i64::MIN..=i64::MAX);
+      assert!(a.range(i64::MIN..=i64::MIN + 1) <= i64::MIN + 1);
+      assert!(a.range(-1..=i64::MAX) >= -1);
+   }
+   assert_eq!(a.range(i64::MAX..=i64::MAX), i64::MAX);
+
+   let mut items: Vec<u32> = (0..20).collect();
+   a.shuffle(&mut items);
+   assert_ne!(items, (0..20).collect::<Vec<_>>());
+   items.sort_unstable();
+   assert_eq!(items, (0..20).collect::<Vec<_>>());
+}
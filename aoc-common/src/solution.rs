@@ -0,0 +1,34 @@
+use std::fmt;
+
+use crate::parse::Result;
+
+// -
+
+// One day's puzzle: parse the input once, then answer either part from the
+// parsed form.
+pub trait Solution {
+   type Input;
+   type Answer1: fmt::Display;
+   type Answer2: fmt::Display;
+
+   const DAY: u32;
+   // Day 25 only has the one puzzle.
+   const HAS_PART2: bool = true;
+
+   fn parse(input: &str) -> Result<Self::Input>;
+   fn part1(input: &Self::Input) -> Self::Answer1;
+   fn part2(input: &Self::Input) -> Self::Answer2;
+
+   // Parse, tagging any error with the day.
+   fn load(input: &str) -> Result<Self::Input> {
+      Self::parse(input).map_err(|e| e.on_day(Self::DAY))
+   }
+
+   // Parse and solve in one go, for tests. Panics on bad input.
+   fn solve1(input: &str) -> Self::Answer1 {
+      Self::part1(&Self::load(input).unwrap())
+   }
+   fn solve2(input: &str) -> Self::Answer2 {
+      Self::part2(&Self::load(input).unwrap())
+   }
+}
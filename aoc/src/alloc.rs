@@ -0,0 +1,67 @@
+// Counts heap usage, so we can report each stage's peak allocation.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// -
+
+pub struct Counting;
+
+static CUR: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+fn grew(by: usize) {
+   let cur = CUR.fetch_add(by, Ordering::Relaxed) + by;
+   PEAK.fetch_max(cur, Ordering::Relaxed);
+}
+
+fn shrank(by: usize) {
+   CUR.fetch_sub(by, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+   unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+      let ret = System.alloc(layout);
+      if !ret.is_null() {
+         grew(layout.size());
+      }
+      ret
+   }
+   unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+      let ret = System.alloc_zeroed(layout);
+      if !ret.is_null() {
+         grew(layout.size());
+      }
+      ret
+   }
+   unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+      System.dealloc(ptr, layout);
+      shrank(layout.size());
+   }
+   unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize)
+         -> *mut u8 {
+      let ret = System.realloc(ptr, layout, new_size);
+      if !ret.is_null() {
+         if new_size > layout.size() {
+            grew(new_size - layout.size());
+         } else {
+            shrank(layout.size() - new_size);
+         }
+      }
+      ret
+   }
+}
+
+// Starts a new peak from what's allocated now.
+// Returns that, for passing to `peak_since`.
+pub fn reset_peak() -> usize {
+   let cur = CUR.load(Ordering::Relaxed);
+   PEAK.store(cur, Ordering::Relaxed);
+   cur
+}
+
+// Bytes allocated at the peak since `reset_peak`, beyond what was already
+// allocated then.
+pub fn peak_since(base: usize) -> usize {
+   PEAK.load(Ordering::Relaxed).saturating_sub(base)
+}
@@ -0,0 +1,97 @@
+// Expected answers for the checked-in inputs, for `aoc verify`.
+//
+// One answer per part, as `run` prints them:
+//
+//    day7 part1: 347509
+//    day13 part2:
+//    #..#.#....###..
+//    ...
+//
+// Lines up to the next `dayN partM:` belong to a multi-line answer.
+// `//` comments and blank lines are skipped.
+
+use std::collections::BTreeMap;
+
+use aoc_common::{Result, Source};
+
+// -
+
+pub const ANSWERS_PATH: &str =
+   concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt");
+
+// By (day, part).
+pub type Answers = BTreeMap<(u32,u32), String>;
+
+pub fn parse(input: &str) -> Result<Answers> {
+   let src = Source::new(input);
+   let mut ret = Answers::new();
+   let mut cur: Option<(u32,u32)> = None;
+   for line in input.lines() {
+      if line.trim().is_empty() || line.starts_with("//") {
+         continue;
+      }
+      if line.starts_with("day") {
+         // "day7 part1: 347509"
+         let s = src.strip_prefix(line, "day")?;
+         let (day_s, s) = src.split_once(s, " part")?;
+         let (part_s, answer) = src.split_once(s, ":")?;
+         let day = src.parse(day_s, "a day")?;
+         let part = src.parse(part_s, "a part")?;
+         if ret.insert((day, part), answer.trim().to_string()).is_some() {
+            return Err(src.error(line, "one answer per part"));
+         }
+         cur = Some((day, part));
+         continue;
+      }
+      let key = cur.ok_or_else(|| src.error(line, "\"dayN partM:\""))?;
+      let answer = ret.get_mut(&key).unwrap();
+      if !answer.is_empty() {
+         answer.push('\n');
+      }
+      answer.push_str(line.trim_end());
+   }
+   Ok(ret)
+}
+
+pub enum Check<'a> {
+   Pass,
+   Fail{expected: &'a str},
+   Missing,
+}
+
+pub fn check<'a>(answers: &'a Answers, day: u32, part: u32, answer: &str)
+      -> Check<'a> {
+   match answers.get(&(day, part)) {
+      None => Check::Missing,
+      Some(expected) if expected == answer.trim_end() => Check::Pass,
+      Some(expected) => Check::Fail{expected},
+   }
+}
+
+// -
+
+#[test]
+fn test_parse() {
+   let answers = parse("\
+// Comment
+day7 part1: 347509
+
+day13 part2:
+#..#
+####
+day25 part1: 513
+").unwrap();
+   assert_eq!(answers.len(), 3);
+   assert_eq!(answers[&(13,2)], "#..#\n####");
+   assert!(matches!(check(&answers, 7, 1, "347509"), Check::Pass));
+   assert!(matches!(check(&answers, 13, 2, "#..#\n####\n"), Check::Pass));
+   assert!(matches!(check(&answers, 25, 1, "512"),
+                    Check::Fail{expected: "513"}));
+   assert!(matches!(check(&answers, 7, 2, "1"), Check::Missing));
+
+   let e = parse("day7 part1: 1\nday7 part1: 2\n").unwrap_err();
+   assert_eq!(e.line, 2);
+   let e = parse("#..#\n").unwrap_err();
+   assert_eq!(e.to_string(),
+              "line 1, column 1: expected \"dayN partM:\", found \"#..#\"");
+}
@@ -0,0 +1,232 @@
+// `aoc bench`: time each stage over many runs, and keep a local history so
+// regressions between commits show up.
+//
+// The history is one JSON object per line, oldest first:
+//
+//    {"commit":"c6798b7","time":1639958400,"day":15,"stage":"part1",...}
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::process::Command;
+use std::time::Duration;
+
+use aoc_common::{Result, Source};
+
+use crate::registry::Day;
+
+// -
+
+pub const HISTORY_PATH: &str =
+   concat!(env!("CARGO_MANIFEST_DIR"), "/../bench-history.jsonl");
+
+// One stage of one day, over every run.
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub struct Record {
+   pub commit: String,
+   // Unix seconds.
+   pub time: u64,
+   pub day: u32,
+   // "parse", "part1" or "part2".
+   pub stage: String,
+   pub runs: u64,
+   pub median_ns: u64,
+   pub min_ns: u64,
+   pub max_ns: u64,
+   pub peak_bytes: u64,
+}
+
+impl Record {
+   fn new(commit: &str, time: u64, day: u32, stage: String,
+          mut samples: Vec<Duration>, peak_bytes: usize) -> Record {
+      samples.sort();
+      let n = samples.len();
+      let median = if n % 2 == 1 {
+         samples[n/2]
+      } else {
+         (samples[n/2 - 1] + samples[n/2]) / 2
+      };
+      Record{
+         commit: commit.to_string(),
+         time,
+         day,
+         stage,
+         runs: n as u64,
+         median_ns: median.as_nanos() as u64,
+         min_ns: samples[0].as_nanos() as u64,
+         max_ns: samples[n-1].as_nanos() as u64,
+         peak_bytes: peak_bytes as u64,
+      }
+   }
+
+   pub fn to_json(&self) -> String {
+      format!("{{\"commit\":{:?},\"time\":{},\"day\":{},\"stage\":{:?},\
+               \"runs\":{},\"median_ns\":{},\"min_ns\":{},\"max_ns\":{},\
+               \"peak_bytes\":{}}}",
+              self.commit, self.time, self.day, self.stage, self.runs,
+              self.median_ns, self.min_ns, self.max_ns, self.peak_bytes)
+   }
+
+   // Only the flat objects that to_json writes.
+   fn from_json(src: &Source, line: &str) -> Result<Record> {
+      let body = src.strip_prefix(line.trim(), "{")?;
+      let body = body.strip_suffix('}').ok_or_else(|| {
+         src.error(&body[body.len()..], "\"}\"")
+      })?;
+      let mut fields = HashMap::new();
+      for field in body.split(',') {
+         let (k, v) = src.split_once(field, ":")?;
+         fields.insert(k.trim().trim_matches('"'), v.trim());
+      }
+      let get = |k: &str| {
+         fields.get(k).copied()
+            .ok_or_else(|| src.error(line, &format!("a {:?} field", k)))
+      };
+      let num = |k: &str| -> Result<u64> {
+         src.parse(get(k)?, "a number")
+      };
+      let string = |k: &str| -> Result<String> {
+         let v = get(k)?;
+         v.strip_prefix('"').and_then(|v| v.strip_suffix('"'))
+            .map(|v| v.to_string())
+            .ok_or_else(|| src.error(v, "a string"))
+      };
+      Ok(Record{
+         commit: string("commit")?,
+         time: num("time")?,
+         day: num("day")? as u32,
+         stage: string("stage")?,
+         runs: num("runs")?,
+         median_ns: num("median_ns")?,
+         min_ns: num("min_ns")?,
+         max_ns: num("max_ns")?,
+         peak_bytes: num("peak_bytes")?,
+      })
+   }
+}
+
+// -
+
+// Runs `parts` of `day` `runs` times, and summarizes each stage.
+pub fn bench(day: &Day, input: &str, parts: &[u32], runs: usize,
+             commit: &str, time: u64) -> Result<Vec<Record>> {
+   // (stage, samples, peak) in the order they ran.
+   let mut stages: Vec<(String, Vec<Duration>, usize)> = Vec::new();
+   for _ in 0..runs {
+      let report = (day.run)(input, parts)?;
+      let mut results = vec![("parse".to_string(), report.parse_elapsed,
+                              report.parse_peak_alloc)];
+      for part in report.parts.iter() {
+         results.push((format!("part{}", part.part), part.elapsed,
+                       part.peak_alloc));
+      }
+      if stages.is_empty() {
+         stages = results.iter()
+            .map(|(stage, _, _)| (stage.clone(), Vec::new(), 0))
+            .collect();
+      }
+      for ((_, samples, peak), (_, elapsed, alloc)) in
+            stages.iter_mut().zip(results) {
+         samples.push(elapsed);
+         *peak = (*peak).max(alloc);
+      }
+   }
+   Ok(stages.into_iter().map(|(stage, samples, peak)| {
+      Record::new(commit, time, day.day, stage, samples, peak)
+   }).collect())
+}
+
+// E.g. "c6798b7", or "c6798b7-dirty" with uncommitted changes.
+pub fn current_commit() -> String {
+   Command::new("git")
+      .args(["describe", "--always", "--dirty"])
+      .current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+      .output().ok()
+      .filter(|out| out.status.success())
+      .and_then(|out| String::from_utf8(out.stdout).ok())
+      .map(|s| s.trim().to_string())
+      .unwrap_or_else(|| "unknown".to_string())
+}
+
+// -
+
+pub fn parse_history(input: &str) -> Result<Vec<Record>> {
+   let src = Source::new(input);
+   input.lines()
+      .filter(|line| !line.trim().is_empty())
+      .map(|line| Record::from_json(&src, line))
+      .collect()
+}
+
+// No history yet is fine.
+pub fn load_history(path: &str) -> std::result::Result<Vec<Record>, String> {
+   let input = match fs::read_to_string(path) {
+      Ok(input) => input,
+      Err(_) => return Ok(Vec::new()),
+   };
+   parse_history(&input).map_err(|e| format!("{}: {}", path, e))
+}
+
+pub fn append_history(path: &str, records: &[Record])
+      -> std::result::Result<(), String> {
+   let mut file = fs::OpenOptions::new().create(true).append(true)
+      .open(path)
+      .map_err(|e| format!("Opening {}: {}", path, e))?;
+   for record in records.iter() {
+      writeln!(file, "{}", record.to_json())
+         .map_err(|e| format!("Writing {}: {}", path, e))?;
+   }
+   Ok(())
+}
+
+// The most recent run of the same stage, for comparison.
+pub fn previous<'a>(history: &'a [Record], record: &Record)
+      -> Option<&'a Record> {
+   history.iter().rev()
+      .find(|r| r.day == record.day && r.stage == record.stage)
+}
+
+// -
+
+pub fn fmt_bytes(bytes: u64) -> String {
+   const UNITS: &[&str] = &["KiB", "MiB", "GiB"];
+   if bytes < 1024 {
+      return format!("{} B", bytes);
+   }
+   let mut val = bytes as f64 / 1024.0;
+   let mut unit = 0;
+   while val >= 1024.0 && unit + 1 < UNITS.len() {
+      val /= 1024.0;
+      unit += 1;
+   }
+   format!("{:.1} {}", val, UNITS[unit])
+}
+
+// -
+
+#[test]
+fn test_history() {
+   let samples = [3, 1, 2, 10].iter()
+      .map(|&ms| Duration::from_millis(ms)).collect();
+   let record = Record::new("c6798b7", 1639958400, 15, "part1".to_string(),
+                            samples, 2048);
+   assert_eq!(record.median_ns, 2_500_000);
+   assert_eq!(record.min_ns, 1_000_000);
+   assert_eq!(record.max_ns, 10_000_000);
+
+   let json = record.to_json();
+   assert_eq!(json, "{\"commit\":\"c6798b7\",\"time\":1639958400,\"day\":15,\
+                     \"stage\":\"part1\",\"runs\":4,\"median_ns\":2500000,\
+                     \"min_ns\":1000000,\"max_ns\":10000000,\
+                     \"peak_bytes\":2048}");
+   let history = parse_history(&format!("{}\n\n{}\n", json, json)).unwrap();
+   assert_eq!(history, [record.clone(), record.clone()]);
+   assert_eq!(previous(&history, &record), Some(&history[1]));
+
+   let e = parse_history("{\"commit\":\"x\"}\n").unwrap_err();
+   assert_eq!(e.expected, "a \"time\" field");
+
+   assert_eq!(fmt_bytes(1000), "1000 B");
+   assert_eq!(fmt_bytes(1536), "1.5 KiB");
+   assert_eq!(fmt_bytes(3 << 20), "3.0 MiB");
+}
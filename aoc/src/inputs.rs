@@ -0,0 +1,235 @@
+// Puzzle inputs, cached under one directory by year and day:
+//
+//    inputs/2021/day7.txt
+//
+// A missing input is downloaded once, with the session cookie from the
+// site, and never again. Requests are spaced at least `min_interval` apart,
+// so `run all` on a fresh checkout doesn't hammer the server.
+
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use log::info;
+
+// -
+
+pub const INPUTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs");
+pub const BASE_URL: &str = "https://adventofcode.com";
+// The value of the site's `session` cookie, from a logged-in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+pub trait Http {
+   // The body of a successful GET of `url`, sending `session` as the
+   // `session` cookie.
+   fn get(&self, url: &str, session: &str) -> Result<String, String>;
+}
+
+// Shells out to curl, which already speaks TLS. The cookie goes in on stdin,
+// since anyone on the machine can read a command line.
+pub struct Curl;
+
+impl Http for Curl {
+   fn get(&self, url: &str, session: &str) -> Result<String, String> {
+      let mut child = Command::new("curl")
+         .args(["--silent", "--show-error", "--fail", "--location"])
+         .args(["--user-agent", "github.com/jdashg/aoc2021 input fetcher"])
+         .args(["--header", "@-"])
+         .arg(url)
+         .stdin(Stdio::piped())
+         .stdout(Stdio::piped())
+         .stderr(Stdio::piped())
+         .spawn()
+         .map_err(|e| format!("Running curl: {}", e))?;
+      // Dropped once written, so curl sees the end of it.
+      let mut stdin = child.stdin.take().unwrap();
+      writeln!(stdin, "Cookie: session={}", session)
+         .map_err(|e| format!("Running curl: {}", e))?;
+      drop(stdin);
+      let out = child.wait_with_output()
+         .map_err(|e| format!("Running curl: {}", e))?;
+      if !out.status.success() {
+         return Err(format!("GET {}: {}", url,
+                            String::from_utf8_lossy(&out.stderr).trim()));
+      }
+      String::from_utf8(out.stdout)
+         .map_err(|e| format!("GET {}: {}", url, e))
+   }
+}
+
+// -
+
+pub struct Inputs<H: Http> {
+   dir: PathBuf,
+   base_url: String,
+   session: Option<String>,
+   http: H,
+   pub min_interval: Duration,
+   last_request: Option<Instant>,
+}
+
+impl Inputs<Curl> {
+   // The checked-in cache, downloading from the site with $AOC_SESSION.
+   pub fn from_env() -> Inputs<Curl> {
+      let session = std::env::var(SESSION_VAR).ok()
+         .map(|s| s.trim().to_string())
+         .filter(|s| !s.is_empty());
+      Inputs::new(INPUTS_DIR, BASE_URL, session, Curl)
+   }
+}
+
+impl<H: Http> Inputs<H> {
+   pub fn new(dir: impl Into<PathBuf>, base_url: &str,
+              session: Option<String>, http: H) -> Inputs<H> {
+      Inputs{
+         dir: dir.into(),
+         base_url: base_url.trim_end_matches('/').to_string(),
+         session,
+         http,
+         min_interval: Duration::from_secs(3),
+         last_request: None,
+      }
+   }
+
+   pub fn path(&self, year: u32, day: u32) -> PathBuf {
+      self.dir.join(year.to_string()).join(format!("day{}.txt", day))
+   }
+
+   pub fn get(&mut self, year: u32, day: u32) -> Result<String, String> {
+      let path = self.path(year, day);
+      if path.exists() {
+         return fs::read_to_string(&path)
+            .map_err(|e| format!("Reading {}: {}", path.display(), e));
+      }
+      let session = self.session.as_ref().ok_or_else(|| {
+         format!("No input at {}, and no ${} to download it with.",
+                 path.display(), SESSION_VAR)
+      })?;
+
+      if let Some(last) = self.last_request {
+         let next = last + self.min_interval;
+         let now = Instant::now();
+         if next > now {
+            thread::sleep(next - now);
+         }
+      }
+      let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+      info!("Downloading {}", url);
+      self.last_request = Some(Instant::now());
+      let input = self.http.get(&url, session)?;
+
+      // Write then rename, so an interrupted download isn't cached.
+      let dir = path.parent().unwrap();
+      fs::create_dir_all(dir)
+         .map_err(|e| format!("Creating {}: {}", dir.display(), e))?;
+      let tmp = path.with_extension("txt.part");
+      fs::write(&tmp, &input)
+         .and_then(|_| fs::rename(&tmp, &path))
+         .map_err(|e| format!("Writing {}: {}", path.display(), e))?;
+      Ok(input)
+   }
+}
+
+// -
+
+#[cfg(test)]
+mod mock {
+   use std::io::{BufRead, BufReader, Write};
+   use std::net::TcpListener;
+   use std::sync::{Arc, Mutex};
+   use std::thread;
+
+   // Serves "/<year>/day/<day>/input" as "input <year> <day>\n", and
+   // records each request's path and cookie.
+   pub struct Server {
+      pub url: String,
+      pub requests: Arc<Mutex<Vec<(String, String)>>>,
+   }
+
+   impl Server {
+      pub fn start() -> Server {
+         let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+         let url = format!("http://{}", listener.local_addr().unwrap());
+         let requests = Arc::new(Mutex::new(Vec::new()));
+         let log = requests.clone();
+         thread::spawn(move || {
+            for stream in listener.incoming() {
+               let mut stream = stream.unwrap();
+               let mut reader = BufReader::new(stream.try_clone().unwrap());
+               let mut line = String::new();
+               reader.read_line(&mut line).unwrap();
+               let path = line.split(' ').nth(1).unwrap_or("").to_string();
+               let mut cookie = String::new();
+               loop {
+                  let mut header = String::new();
+                  reader.read_line(&mut header).unwrap();
+                  if header.trim().is_empty() {
+                     break;
+                  }
+                  if let Some(v) = header.strip_prefix("Cookie: ") {
+                     cookie = v.trim().to_string();
+                  }
+               }
+               let parts: Vec<&str> = path.split('/').collect();
+               let (status, body) = match parts[..] {
+                  ["", year, "day", day, "input"] if cookie == "session=abc" =>
+                     ("200 OK", format!("input {} {}\n", year, day)),
+                  _ => ("404 Not Found", String::new()),
+               };
+               log.lock().unwrap().push((path, cookie));
+               write!(stream, "HTTP/1.1 {}\r\nContent-Length: {}\r\n\
+                               Connection: close\r\n\r\n{}",
+                      status, body.len(), body).unwrap();
+            }
+         });
+         Server{url, requests}
+      }
+   }
+}
+
+#[test]
+fn test_inputs() {
+   let server = mock::Server::start();
+   let dir = std::env::temp_dir()
+      .join(format!("aoc-inputs-{}", std::process::id()));
+   let _ = fs::remove_dir_all(&dir);
+   fs::create_dir_all(dir.join("2021")).unwrap();
+   fs::write(dir.join("2021/day1.txt"), "cached\n").unwrap();
+
+   let mut inputs = Inputs::new(&dir, &server.url, Some("abc".to_string()),
+                                Curl);
+   inputs.min_interval = Duration::from_millis(200);
+
+   // Hits never touch the server.
+   assert_eq!(inputs.get(2021, 1).unwrap(), "cached\n");
+   assert!(server.requests.lock().unwrap().is_empty());
+
+   // Misses download once, then come from the cache.
+   let start = Instant::now();
+   assert_eq!(inputs.get(2021, 2).unwrap(), "input 2021 2\n");
+   assert_eq!(inputs.get(2021, 2).unwrap(), "input 2021 2\n");
+   assert_eq!(fs::read_to_string(dir.join("2021/day2.txt")).unwrap(),
+              "input 2021 2\n");
+   assert_eq!(inputs.get(2020, 3).unwrap(), "input 2020 3\n");
+   assert!(start.elapsed() >= inputs.min_interval);
+   assert_eq!(*server.requests.lock().unwrap(), [
+      ("/2021/day/2/input".to_string(), "session=abc".to_string()),
+      ("/2020/day/3/input".to_string(), "session=abc".to_string()),
+   ]);
+
+   // Failures aren't cached.
+   let mut inputs = Inputs::new(&dir, &server.url, Some("bad".to_string()),
+                                Curl);
+   assert!(inputs.get(2021, 4).is_err());
+   assert!(!inputs.path(2021, 4).exists());
+
+   let mut inputs = Inputs::new(&dir, &server.url, None, Curl);
+   let e = inputs.get(2021, 5).unwrap_err();
+   assert!(e.contains("$AOC_SESSION"), "{}", e);
+   assert_eq!(server.requests.lock().unwrap().len(), 3);
+
+   fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,140 @@
+// Where the days' `log` diagnostics go: stderr, so stdout is only answers.
+//
+//    -v, -vv, -vvv             info, debug or trace for every day.
+//                              (Default: warn)
+//    --log <target>[=<level>]  Override one target, e.g. `--log day23=trace`.
+//                              (Default level: debug)
+//
+// A target matches itself and its submodules, so `day19` covers
+// `day19::scan`. The last matching `--log` wins.
+
+use std::io::Write;
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+// -
+
+pub struct Logger {
+   default: LevelFilter,
+   targets: Vec<(String, LevelFilter)>,
+}
+
+fn verbosity_level(verbosity: usize) -> LevelFilter {
+   match verbosity {
+      0 => LevelFilter::Warn,
+      1 => LevelFilter::Info,
+      2 => LevelFilter::Debug,
+      _ => LevelFilter::Trace,
+   }
+}
+
+impl Logger {
+   // Takes the logging flags out of `args`, wherever they are, and leaves
+   // the rest for the command.
+   pub fn from_args(args: &mut Vec<String>) -> Result<Logger, String> {
+      let mut verbosity = 0;
+      let mut targets = Vec::new();
+      let mut rest = Vec::new();
+      let mut iter = args.drain(..);
+      while let Some(arg) = iter.next() {
+         if arg.len() > 1 && arg.starts_with('-')
+               && arg[1..].chars().all(|c| c == 'v') {
+            verbosity += arg.len() - 1;
+         } else if arg == "--log" {
+            let spec = iter.next()
+               .ok_or_else(|| "Missing value for --log.".to_string())?;
+            targets.push(parse_target(&spec)?);
+         } else {
+            rest.push(arg);
+         }
+      }
+      drop(iter);
+      *args = rest;
+      Ok(Logger{default: verbosity_level(verbosity), targets})
+   }
+
+   fn level_for(&self, target: &str) -> LevelFilter {
+      self.targets.iter().rev()
+         .find(|(t, _)| {
+            target == t
+               || (target.starts_with(t.as_str())
+                   && target[t.len()..].starts_with("::"))
+         })
+         .map(|&(_, level)| level)
+         .unwrap_or(self.default)
+   }
+
+   fn max_level(&self) -> LevelFilter {
+      self.targets.iter().map(|&(_, level)| level)
+         .fold(self.default, |a, b| a.max(b))
+   }
+
+   pub fn install(self) {
+      let max = self.max_level();
+      if log::set_logger(Box::leak(Box::new(self))).is_ok() {
+         log::set_max_level(max);
+      }
+   }
+}
+
+// "day23" or "day23=trace".
+fn parse_target(spec: &str) -> Result<(String, LevelFilter), String> {
+   let (target, level) = match spec.split_once('=') {
+      None => (spec, LevelFilter::Debug),
+      Some((target, level)) => {
+         let level = level.parse()
+            .map_err(|_| format!("Bad --log level: {:?}", level))?;
+         (target, level)
+      },
+   };
+   if target.is_empty() {
+      return Err(format!("Bad --log target: {:?}", spec));
+   }
+   Ok((target.to_string(), level))
+}
+
+impl Log for Logger {
+   fn enabled(&self, metadata: &Metadata) -> bool {
+      metadata.level() <= self.level_for(metadata.target())
+   }
+
+   fn log(&self, record: &Record) {
+      if !self.enabled(record.metadata()) {
+         return;
+      }
+      let stderr = std::io::stderr();
+      let mut stderr = stderr.lock();
+      let _ = writeln!(stderr, "[{} {}] {}", record.level(), record.target(),
+                       record.args());
+   }
+
+   fn flush(&self) {
+      let _ = std::io::stderr().flush();
+   }
+}
+
+// -
+
+#[test]
+fn test_from_args() {
+   let mut args: Vec<String> = ["run", "-v", "19", "--log", "day23=trace",
+                                "--part", "1", "-vv", "--log", "day19"]
+      .iter().map(|s| s.to_string()).collect();
+   let logger = Logger::from_args(&mut args).unwrap();
+   assert_eq!(args, ["run", "19", "--part", "1"]);
+   assert_eq!(logger.default, LevelFilter::Trace);
+   assert_eq!(logger.level_for("day23"), LevelFilter::Trace);
+   assert_eq!(logger.level_for("day19::scan"), LevelFilter::Debug);
+   assert_eq!(logger.level_for("day1"), LevelFilter::Trace);
+   assert_eq!(logger.max_level(), LevelFilter::Trace);
+
+   let mut args = vec!["--log".to_string(), "day1=loud".to_string()];
+   assert!(Logger::from_args(&mut args).is_err());
+
+   let mut args = vec!["--log".to_string(), "day1".to_string()];
+   let logger = Logger::from_args(&mut args).unwrap();
+   assert_eq!(logger.default, LevelFilter::Warn);
+   assert_eq!(logger.level_for("day1"), LevelFilter::Debug);
+   assert_eq!(logger.level_for("day19"), LevelFilter::Warn);
+   assert_eq!(logger.level_for("day1x"), LevelFilter::Warn);
+}
@@ -0,0 +1,398 @@
+use std::fs;
+use std::io::Read;
+use std::process;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc_common::Rng;
+
+mod alloc;
+mod answers;
+mod bench;
+mod inputs;
+mod logger;
+mod registry;
+use answers::Check;
+use inputs::{Curl, Inputs};
+use registry::Day;
+
+#[global_allocator]
+static ALLOC: alloc::Counting = alloc::Counting;
+
+const USAGE: &str = "\
+Usage: aoc run <day|all> [--part <1|2>] [--input <path|->]
+       aoc verify [<day|all>]
+       aoc bench <day|all> [--runs <n>]
+       aoc gen <day> [--size <n>] [--seed <n>]
+       aoc export <day> <format> [--input <path|->]
+
+   Any command also takes:
+   -v, -vv, -vvv     Log info, debug or trace diagnostics to stderr.
+                     (Default: warnings only)
+   --log <day>[=<level>]
+                     Log one day at <level>, e.g. `--log day23=trace`.
+                     (Default level: debug)
+
+   <day>             1-25, or `all` to run every day.
+   --part <1|2>      Only run one part. (Default: both)
+   --input <path|->  Read the puzzle input from a file, or `-` for stdin.
+                     (Default: inputs/2021/dayN.txt, downloaded with
+                     $AOC_SESSION if it's not there yet)
+
+`verify` checks each day's answers for its own input against answers.txt.
+`bench` times parse/part1/part2 over <n> runs (default 10) and appends the
+results to bench-history.jsonl.
+`gen` prints a random input of about <n> (default 10) things for days 16, 18,
+19, 22 and 23, e.g. `aoc gen 18 --seed 3 | aoc run 18 --input -`.
+`export` prints the solved input in another format: for day 19, the scanner
+poses and beacon map as `ply` or `json`, or the scanners' overlaps as `dot`.
+";
+
+// -
+
+struct RunArgs {
+   days: Vec<&'static Day>,
+   part: Option<u32>,
+   input: Option<String>,
+}
+
+fn parse_days(arg: Option<&String>) -> Result<Vec<&'static Day>, String> {
+   match arg.map(|s| s.as_str()) {
+      None => Err("Missing <day>.".to_string()),
+      Some("all") => Ok(registry::DAYS.iter().collect()),
+      Some(s) => {
+         let day = s.parse::<u32>()
+            .map_err(|_| format!("Bad <day>: {:?}", s))?;
+         let day = registry::find(day)
+            .ok_or_else(|| format!("No solution for day {}.", day))?;
+         Ok(vec![day])
+      },
+   }
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+   let mut args = args.iter();
+   let days = parse_days(args.next())?;
+
+   let mut ret = RunArgs{days, part: None, input: None};
+   while let Some(arg) = args.next() {
+      let mut value = || {
+         args.next().ok_or_else(|| format!("Missing value for {}.", arg))
+      };
+      match arg.as_str() {
+         "--part" => {
+            let part = value()?;
+            ret.part = match part.as_str() {
+               "1" => Some(1),
+               "2" => Some(2),
+               _ => return Err(format!("Bad --part: {:?}", part)),
+            };
+         },
+         "--input" => {
+            ret.input = Some(value()?.clone());
+         },
+         _ => return Err(format!("Unrecognized argument: {:?}", arg)),
+      }
+   }
+   if ret.input.is_some() && ret.days.len() > 1 {
+      return Err("--input needs a single <day>.".to_string());
+   }
+   // With `all`, days without the part are just skipped.
+   if let [day] = ret.days[..] {
+      if ret.part == Some(2) && !day.has_part2 {
+         return Err(format!("Day {} has no part 2.", day.day));
+      }
+   }
+   Ok(ret)
+}
+
+fn read_input(inputs: &mut Inputs<Curl>, day: &Day, input: &Option<String>)
+      -> Result<String, String> {
+   let mut ret = String::new();
+   match input.as_deref() {
+      Some("-") => {
+         std::io::stdin().read_to_string(&mut ret)
+            .map_err(|e| format!("Reading stdin: {}", e))?;
+      },
+      Some(path) => {
+         ret = fs::read_to_string(path)
+            .map_err(|e| format!("Reading {}: {}", path, e))?;
+      },
+      None => {
+         ret = inputs.get(registry::YEAR, day.day)?;
+      },
+   }
+   Ok(ret)
+}
+
+fn parts_to_run(day: &Day, part: Option<u32>) -> Vec<u32> {
+   let mut parts = vec![1];
+   if day.has_part2 {
+      parts.push(2);
+   }
+   parts.retain(|p| part.is_none() || part == Some(*p));
+   parts
+}
+
+fn run(args: &RunArgs) -> Result<(), String> {
+   let mut inputs = Inputs::from_env();
+   for day in args.days.iter() {
+      let parts = parts_to_run(day, args.part);
+      if parts.is_empty() {
+         continue;
+      }
+      let input = read_input(&mut inputs, day, &args.input)?;
+
+      let report = (day.run)(&input, &parts).map_err(|e| e.to_string())?;
+      println!("day{} parse: ({:.2?})", day.day, report.parse_elapsed);
+      for part in report.parts.iter() {
+         if part.answer.contains('\n') {
+            println!("day{} part{}: ({:.2?})\n{}", day.day, part.part,
+                     part.elapsed, part.answer.trim_end());
+         } else {
+            println!("day{} part{}: {} ({:.2?})", day.day, part.part,
+                     part.answer, part.elapsed);
+         }
+      }
+   }
+   Ok(())
+}
+
+struct BenchArgs {
+   days: Vec<&'static Day>,
+   runs: usize,
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+   let mut args = args.iter();
+   let days = parse_days(args.next())?;
+   let mut ret = BenchArgs{days, runs: 10};
+   while let Some(arg) = args.next() {
+      match arg.as_str() {
+         "--runs" => {
+            let runs = args.next()
+               .ok_or_else(|| format!("Missing value for {}.", arg))?;
+            ret.runs = match runs.parse() {
+               Ok(n) if n > 0 => n,
+               _ => return Err(format!("Bad --runs: {:?}", runs)),
+            };
+         },
+         _ => return Err(format!("Unrecognized argument: {:?}", arg)),
+      }
+   }
+   Ok(ret)
+}
+
+fn bench(args: &BenchArgs) -> Result<(), String> {
+   let history = bench::load_history(bench::HISTORY_PATH)?;
+   let commit = bench::current_commit();
+   let time = SystemTime::now().duration_since(UNIX_EPOCH)
+      .map(|d| d.as_secs()).unwrap_or(0);
+
+   let mut inputs = Inputs::from_env();
+   let mut records = Vec::new();
+   for day in args.days.iter() {
+      let input = read_input(&mut inputs, day, &None)?;
+      let parts = parts_to_run(day, None);
+      let day_records = bench::bench(day, &input, &parts, args.runs,
+                                     &commit, time)
+         .map_err(|e| e.to_string())?;
+      for r in day_records.iter() {
+         let dur = Duration::from_nanos;
+         let mut line = format!(
+            "day{} {}: median {:.2?}, min {:.2?}, max {:.2?}, peak {}",
+            r.day, r.stage, dur(r.median_ns), dur(r.min_ns), dur(r.max_ns),
+            bench::fmt_bytes(r.peak_bytes));
+         if let Some(prev) = bench::previous(&history, r) {
+            let change = (r.median_ns as f64 / prev.median_ns.max(1) as f64
+                          - 1.0) * 100.0;
+            line += &format!(" ({:+.1}% vs {})", change, prev.commit);
+         }
+         println!("{}", line);
+      }
+      records.extend(day_records);
+   }
+   bench::append_history(bench::HISTORY_PATH, &records)
+}
+
+struct GenArgs {
+   day: &'static Day,
+   size: usize,
+   seed: u64,
+}
+
+fn parse_gen_args(args: &[String]) -> Result<GenArgs, String> {
+   let mut args = args.iter();
+   let day = match parse_days(args.next())?[..] {
+      [day] => day,
+      _ => return Err("gen needs a single <day>.".to_string()),
+   };
+   let mut ret = GenArgs{day, size: 10, seed: 0};
+   while let Some(arg) = args.next() {
+      let value = args.next()
+         .ok_or_else(|| format!("Missing value for {}.", arg))?;
+      match arg.as_str() {
+         "--size" => {
+            ret.size = value.parse()
+               .map_err(|_| format!("Bad --size: {:?}", value))?;
+         },
+         "--seed" => {
+            ret.seed = value.parse()
+               .map_err(|_| format!("Bad --seed: {:?}", value))?;
+         },
+         _ => return Err(format!("Unrecognized argument: {:?}", arg)),
+      }
+   }
+   Ok(ret)
+}
+
+fn gen(args: &GenArgs) -> Result<(), String> {
+   let gen = args.day.gen
+      .ok_or_else(|| format!("No generator for day {}.", args.day.day))?;
+   print!("{}", gen(&mut Rng::new(args.seed), args.size));
+   Ok(())
+}
+
+struct ExportArgs {
+   day: &'static Day,
+   format: String,
+   input: Option<String>,
+}
+
+fn parse_export_args(args: &[String]) -> Result<ExportArgs, String> {
+   let mut args = args.iter();
+   let day = match parse_days(args.next())?[..] {
+      [day] => day,
+      _ => return Err("export needs a single <day>.".to_string()),
+   };
+   let format = args.next().ok_or("Missing <format>.")?.clone();
+   let mut ret = ExportArgs{day, format, input: None};
+   while let Some(arg) = args.next() {
+      let value = args.next()
+         .ok_or_else(|| format!("Missing value for {}.", arg))?;
+      match arg.as_str() {
+         "--input" => ret.input = Some(value.clone()),
+         _ => return Err(format!("Unrecognized argument: {:?}", arg)),
+      }
+   }
+   Ok(ret)
+}
+
+fn export(args: &ExportArgs) -> Result<(), String> {
+   let export = args.day.export
+      .ok_or_else(|| format!("No export for day {}.", args.day.day))?;
+   let input = read_input(&mut Inputs::from_env(), args.day, &args.input)?;
+   print!("{}", export(&input, &args.format)?);
+   Ok(())
+}
+
+// Runs every part of `days` on its own input.
+// Returns whether everything passed.
+fn verify(days: &[&'static Day]) -> Result<bool, String> {
+   let answers = fs::read_to_string(answers::ANSWERS_PATH)
+      .map_err(|e| format!("Reading {}: {}", answers::ANSWERS_PATH, e))?;
+   let answers = answers::parse(&answers)
+      .map_err(|e| format!("{}: {}", answers::ANSWERS_PATH, e))?;
+
+   let mut inputs = Inputs::from_env();
+   let (mut passed, mut failed, mut missing) = (0, 0, 0);
+   for day in days.iter() {
+      let parts = parts_to_run(day, None);
+      // Without its input, a day fails, but the rest still get checked.
+      let input = match read_input(&mut inputs, day, &None) {
+         Ok(input) => input,
+         Err(e) => {
+            println!("day{}: FAIL: {}", day.day, e);
+            failed += parts.len();
+            continue;
+         },
+      };
+      let report = match (day.run)(&input, &parts) {
+         Ok(report) => report,
+         Err(e) => {
+            println!("day{}: FAIL: {}", day.day, e);
+            failed += parts.len();
+            continue;
+         },
+      };
+      for part in report.parts.iter() {
+         let name = format!("day{} part{}", day.day, part.part);
+         match answers::check(&answers, day.day, part.part, &part.answer) {
+            Check::Pass => {
+               passed += 1;
+               println!("{}: pass", name);
+            },
+            Check::Fail{expected} => {
+               failed += 1;
+               println!("{}: FAIL\n   expected: {}\n   got: {}", name,
+                        expected.replace('\n', "\n   "),
+                        part.answer.trim_end().replace('\n', "\n   "));
+            },
+            Check::Missing => {
+               missing += 1;
+               println!("{}: missing (got {})", name,
+                        part.answer.trim_end().replace('\n', "\n   "));
+            },
+         }
+      }
+   }
+   println!("{} passed, {} failed, {} missing", passed, failed, missing);
+   Ok(failed == 0 && missing == 0)
+}
+
+fn usage_error(e: &str) -> ! {
+   eprintln!("Error: {}\n\n{}", e, USAGE);
+   process::exit(2);
+}
+
+fn main() {
+   let mut args: Vec<String> = std::env::args().skip(1).collect();
+   logger::Logger::from_args(&mut args)
+      .unwrap_or_else(|e| usage_error(&e))
+      .install();
+   let res = match args.first().map(|s| s.as_str()) {
+      Some("run") => {
+         let run_args = parse_run_args(&args[1..])
+            .unwrap_or_else(|e| usage_error(&e));
+         run(&run_args)
+      },
+      Some("bench") => {
+         let bench_args = parse_bench_args(&args[1..])
+            .unwrap_or_else(|e| usage_error(&e));
+         bench(&bench_args)
+      },
+      Some("gen") => {
+         let gen_args = parse_gen_args(&args[1..])
+            .unwrap_or_else(|e| usage_error(&e));
+         gen(&gen_args)
+      },
+      Some("export") => {
+         let export_args = parse_export_args(&args[1..])
+            .unwrap_or_else(|e| usage_error(&e));
+         export(&export_args)
+      },
+      Some("verify") => {
+         let days = match args.get(1) {
+            None => registry::DAYS.iter().collect(),
+            arg => parse_days(arg).unwrap_or_else(|e| usage_error(&e)),
+         };
+         if args.len() > 2 {
+            usage_error(&format!("Unrecognized argument: {:?}", args[2]));
+         }
+         match verify(&days) {
+            Ok(true) => Ok(()),
+            Ok(false) => process::exit(1),
+            Err(e) => Err(e),
+         }
+      },
+      Some("help") | Some("--help") | Some("-h") => {
+         print!("{}", USAGE);
+         return;
+      },
+      Some(cmd) => usage_error(&format!("Unknown command: {:?}", cmd)),
+      None => usage_error("Missing command."),
+   };
+   if let Err(e) = res {
+      eprintln!("Error: {}", e);
+      process::exit(1);
+   }
+}
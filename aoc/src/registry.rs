@@ -0,0 +1,116 @@
+// Every day's solution, in order.
+
+use std::time::{Duration, Instant};
+
+use aoc_common::{ParseError, Rng, Solution};
+
+use crate::alloc;
+
+// -
+
+pub const YEAR: u32 = 2021;
+
+pub struct PartReport {
+   pub part: u32,
+   pub answer: String,
+   pub elapsed: Duration,
+   // Bytes.
+   pub peak_alloc: usize,
+}
+
+pub struct Report {
+   pub parse_elapsed: Duration,
+   pub parse_peak_alloc: usize,
+   pub parts: Vec<PartReport>,
+}
+
+pub struct Day {
+   pub day: u32,
+   // Day 25 only has the one puzzle.
+   pub has_part2: bool,
+   // Parses the input once, then runs each of `parts`.
+   pub run: fn(input: &str, parts: &[u32]) -> Result<Report, ParseError>,
+   // A random input of about `size`, for days with a `gen` module.
+   pub gen: Option<fn(rng: &mut Rng, size: usize) -> String>,
+   // The solved input in some other `format`, for days with an `export`
+   // module.
+   pub export: Option<Export>,
+}
+
+pub type Export = fn(input: &str, format: &str) -> Result<String, String>;
+
+pub fn run<S: Solution>(input: &str, parts: &[u32])
+      -> Result<Report, ParseError> {
+   let alloc_base = alloc::reset_peak();
+   let start = Instant::now();
+   let input = S::load(input)?;
+   let parse_elapsed = start.elapsed();
+   let parse_peak_alloc = alloc::peak_since(alloc_base);
+
+   let parts = parts.iter().map(|&part| {
+      let alloc_base = alloc::reset_peak();
+      let start = Instant::now();
+      let answer = match part {
+         1 => S::part1(&input).to_string(),
+         2 => S::part2(&input).to_string(),
+         _ => panic!("{}", part),
+      };
+      PartReport{part, answer, elapsed: start.elapsed(),
+                 peak_alloc: alloc::peak_since(alloc_base)}
+   }).collect();
+   Ok(Report{parse_elapsed, parse_peak_alloc, parts})
+}
+
+macro_rules! day {
+   ($krate:ident::$solution:ident) => {
+      day!($krate::$solution, None, None)
+   };
+   ($krate:ident::$solution:ident, gen) => {
+      day!($krate::$solution, Some($krate::gen::input), None)
+   };
+   ($krate:ident::$solution:ident, gen, export) => {
+      day!($krate::$solution, Some($krate::gen::input),
+           Some($krate::export::export))
+   };
+   ($krate:ident::$solution:ident, $gen:expr, $export:expr) => {
+      Day {
+         day: <$krate::$solution as Solution>::DAY,
+         has_part2: <$krate::$solution as Solution>::HAS_PART2,
+         run: run::<$krate::$solution>,
+         gen: $gen,
+         export: $export,
+      }
+   };
+}
+
+pub const DAYS: &[Day] = &[
+   day!(day1::Day1),
+   day!(day2::Day2),
+   day!(day3::Day3),
+   day!(day4::Day4),
+   day!(day5::Day5),
+   day!(day6::Day6),
+   day!(day7::Day7),
+   day!(day8::Day8),
+   day!(day9::Day9),
+   day!(day10::Day10),
+   day!(day11::Day11),
+   day!(day12::Day12),
+   day!(day13::Day13),
+   day!(day14::Day14),
+   day!(day15::Day15),
+   day!(day16::Day16, gen),
+   day!(day17::Day17),
+   day!(day18::Day18, gen),
+   day!(day19::Day19, gen, export),
+   day!(day20::Day20),
+   day!(day21::Day21),
+   day!(day22::Day22, gen),
+   day!(day23::Day23, gen),
+   day!(day24::Day24),
+   day!(day25::Day25),
+];
+
+pub fn find(day: u32) -> Option<&'static Day> {
+   DAYS.iter().find(|d| d.day == day)
+}
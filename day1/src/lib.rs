@@ -0,0 +1,82 @@
+use std::collections::VecDeque;
+use aoc_common::{Result, Solution, Source};
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+   type Input = Vec<i64>;
+   type Answer1 = usize;
+   type Answer2 = usize;
+   const DAY: u32 = 1;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse_inputs(input)
+   }
+   fn part1(depths: &Self::Input) -> usize {
+      part1(depths)
+   }
+   fn part2(depths: &Self::Input) -> usize {
+      part2(depths)
+   }
+}
+
+// -
+
+fn parse_inputs(input: &str) -> Result<Vec<i64>> {
+   let src = Source::new(input);
+   src.not_empty("a depth")?;
+   input.split('\n')
+      .filter(|x| !x.is_empty())
+      .map(|x| src.parse(x, "a depth"))
+      .collect()
+}
+
+// "How many measurements are larger than the previous measurement?"
+fn part1(depths: &[i64]) -> usize {
+   let mut prev = depths[0];
+   let mut num_increases = 0;
+   for &cur in depths[1..].iter() {
+      let diff = cur - prev;
+      if diff > 0 {
+         num_increases += 1;
+      }
+      prev = cur;
+   }
+   num_increases
+}
+
+// "Consider sums of a three-measurement sliding window. How many sums are
+//  larger than the previous sum?"
+fn part2(inputs: &[i64]) -> usize {
+   let mut window = VecDeque::<i64>::new();
+   let mut prev_sum = None;
+   let mut num_increases = 0;
+
+   for &input in inputs {
+      window.push_back(input);
+      if window.len() < 3 { continue; }
+      while window.len() > 3 {
+         window.pop_front();
+      }
+      let cur_sum: i64 = window.iter().sum();
+      if let Some(prev_sum) = prev_sum {
+         if cur_sum > prev_sum {
+            num_increases += 1;
+         }
+      }
+      prev_sum = Some(cur_sum);
+   }
+   num_increases
+}
+
+// -
+
+#[test]
+fn test_example() {
+   let input = fixture!("example.txt");
+   assert_eq!(Day1::solve1(input), 7);
+   assert_eq!(Day1::solve2(input), 5);
+   assert!(Day1::load("").is_err());
+}
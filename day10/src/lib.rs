@@ -0,0 +1,120 @@
+use aoc_common::{Result, Solution, Source};
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+   type Input = Vec<String>;
+   type Answer1 = usize;
+   type Answer2 = usize;
+   const DAY: u32 = 10;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse_inputs(input)
+   }
+   fn part1(lines: &Self::Input) -> usize {
+      part1(lines)
+   }
+   fn part2(lines: &Self::Input) -> usize {
+      part2(lines)
+   }
+}
+
+// -
+
+fn parse_inputs(input: &str) -> Result<Vec<String>> {
+   let src = Source::new(input);
+   src.not_empty("a line of brackets")?;
+   input.trim().split('\n').map(|x| {
+      if let Some(bad) = x.find(|c| !"([{<>}])".contains(c)) {
+         return Err(src.error(&x[bad..bad+1], "a bracket"));
+      }
+      Ok(x.to_string())
+   }).collect()
+}
+
+enum LineCheck {
+   Corrupted(char),
+   // Closers needed to complete the line, innermost first.
+   Incomplete(Vec<char>),
+}
+
+fn check_line(line: &str) -> LineCheck {
+   let mut stack = Vec::new();
+   for c in line.chars() {
+      match c {
+         '(' => stack.push(')'),
+         '[' => stack.push(']'),
+         '{' => stack.push('}'),
+         '<' => stack.push('>'),
+         _ => {
+            if c != stack.pop().unwrap_or('x') {
+               return LineCheck::Corrupted(c);
+            }
+         },
+      };
+   }
+   stack.reverse();
+   LineCheck::Incomplete(stack)
+}
+
+// "Find the first illegal character in each corrupted line of the
+//  navigation subsystem. What is the total syntax error score for those
+//  errors?"
+fn part1(lines: &[String]) -> usize {
+   lines.iter()
+      .map(|line| {
+         match check_line(line) {
+            LineCheck::Corrupted(c) => points_by_illegal_char(c),
+            LineCheck::Incomplete(_) => 0,
+         }
+      }).sum()
+}
+
+// "Find the completion string for each incomplete line, score the
+//  completion strings, and sort the scores. What is the middle score?"
+fn part2(lines: &[String]) -> usize {
+   let mut scores: Vec<_> = lines.iter()
+      .map(|line| {
+         match check_line(line) {
+            LineCheck::Corrupted(_) => 0,
+            LineCheck::Incomplete(closers) => {
+               closers.into_iter().map(points_by_char)
+                  .fold(0, |prev,next| 5*prev + next)
+            },
+         }
+      }).filter(|x| *x != 0).collect();
+   scores.sort_unstable();
+   scores[scores.len()/2]
+}
+
+fn points_by_illegal_char(c: char) -> usize {
+   match c {
+      ')' => 3,
+      ']' => 57,
+      '}' => 1197,
+      '>' => 25137,
+      _ => panic!("{}", c),
+   }
+}
+
+fn points_by_char(c: char) -> usize {
+   match c {
+      ')' => 1,
+      ']' => 2,
+      '}' => 3,
+      '>' => 4,
+      _ => panic!("{}", c),
+   }
+}
+
+// -
+
+#[test]
+fn test_example() {
+   let input = fixture!("example.txt");
+   assert_eq!(Day10::solve1(input), 26397);
+   assert_eq!(Day10::solve2(input), 288957);
+   assert!(Day10::load("").is_err());
+}
@@ -0,0 +1,113 @@
+use aoc_common::{Grid, Point, Result, Solution};
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+   type Input = Grid<usize>;
+   type Answer1 = usize;
+   type Answer2 = usize;
+   const DAY: u32 = 11;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse_inputs(input)
+   }
+   // "How many total flashes are there after 100 steps?"
+   fn part1(grid: &Self::Input) -> usize {
+      flashes_after(grid, 100)
+   }
+   fn part2(grid: &Self::Input) -> usize {
+      part2(grid)
+   }
+}
+
+// -
+
+fn step(grid: &mut Grid<usize>) -> usize {
+   let size = grid.len();
+   let mut flashes = 0;
+
+   // "First, the energy level of each octopus increases by 1."
+
+   fn inc_energy(p: &Point, val: &mut usize, to_flash: &mut Vec<Point>) {
+      *val += 1;
+      if *val == 10 {
+         to_flash.push(*p);
+      }
+   }
+
+   let mut to_flash = Vec::new();
+   for y in 1..(size.y-1) {
+      for x in 1..(size.x-1) {
+         let p = Point{x, y};
+         inc_energy(&p, grid.at_mut(&p), &mut to_flash);
+      }
+   }
+
+   while let Some(p) = to_flash.pop() {
+      flashes += 1;
+      p.nearest8().iter().for_each(|p| {
+         inc_energy(p, grid.at_mut(p), &mut to_flash);
+      });
+   }
+
+   // "Finally, any octopus that flashed during this step has its
+   //  energy level set to 0, as it used all of its energy to flash."
+   for y in 1..(size.y-1) {
+      for x in 1..(size.x-1) {
+         let p = Point{x, y};
+         let val = grid.at_mut(&p);
+         if *val > 9 {
+            *val = 0;
+         }
+      }
+   }
+   grid.set_border(0);
+   flashes
+}
+
+
+// -
+
+fn parse_inputs(input: &str) -> Result<Grid<usize>> {
+   Grid::from_digits(input)
+}
+
+fn flashes_after(grid: &Grid<usize>, steps: usize) -> usize {
+   let mut grid = grid.clone();
+   grid.insert_border(0);
+
+   (0..steps).map(|_| {
+      step(&mut grid)
+   }).sum()
+}
+
+// "What is the first step during which all octopuses flash?"
+fn part2(grid: &Grid<usize>) -> usize {
+   let mut grid = grid.clone();
+   let size = grid.len();
+   let num_octos = (size.x * size.y) as usize;
+
+   grid.insert_border(0);
+
+   for i in 1..1000 {
+      let flashes = step(&mut grid);
+      if flashes == num_octos { return i; }
+   }
+   panic!("Not found");
+}
+
+// -
+
+#[test]
+fn test_example() {
+   let input = fixture!("example.txt");
+   let grid = Day11::parse(input).unwrap();
+   assert_eq!(flashes_after(&grid, 0), 0);
+   assert_eq!(flashes_after(&grid, 1), 0);
+   assert_eq!(flashes_after(&grid, 2), 35);
+   assert_eq!(Day11::part1(&grid), 1656);
+   assert_eq!(Day11::part2(&grid), 195);
+   assert!(Day11::load("").is_err());
+}
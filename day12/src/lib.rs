@@ -1,27 +1,51 @@
-use std::fs::File;
-use std::io::Read;
-use std::path::Path;
 use std::collections::HashMap;
+use aoc_common::{Result, Solution, Source};
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+   type Input = Graph;
+   type Answer1 = usize;
+   type Answer2 = usize;
+   const DAY: u32 = 12;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse_inputs(input)
+   }
+   // "How many paths through this cave system are there that visit small
+   //  caves at most once?"
+   fn part1(graph: &Self::Input) -> usize {
+      count_paths(graph, false)
+   }
+   // "Given these new rules, how many paths through this cave system are
+   //  there?"
+   fn part2(graph: &Self::Input) -> usize {
+      count_paths(graph, true)
+   }
+}
 
 // -
 
-struct Graph {
+pub struct Graph {
    neighbors: HashMap<String,Vec<String>>,
 }
 impl Graph {
    fn add_directed_edge(&mut self, from: String, to: String) {
-      let e = self.neighbors.entry(from).or_insert(Vec::new());
+      let e = self.neighbors.entry(from).or_default();
       e.push(to);
    }
 }
 
-fn parse_inputs(input: &str) -> Graph {
+fn parse_inputs(input: &str) -> Result<Graph> {
+   let src = Source::new(input);
    let mut graph = Graph{
       neighbors: HashMap::new()
    };
 
    for line in input.trim().split("\n") {
-      let (left, right) = line.split_once("-").unwrap();
+      let (left, right) = src.split_once(line, "-")?;
       let mut left_to_right = true;
       let mut right_to_left = true;
       if left == "start" {
@@ -43,7 +67,7 @@ fn parse_inputs(input: &str) -> Graph {
          graph.add_directed_edge(right.to_string(), left.to_string());
       }
    }
-   graph
+   Ok(graph)
 }
 
 fn is_small(s: &String) -> bool {
@@ -57,7 +81,7 @@ fn enumerate_paths(graph: &Graph, did_small_cave_twice: bool, path_stack: &mut V
    let mut ret = Vec::new();
    for next in graph.neighbors[&cur].iter() {
       let mut next_did_small_cave_twice = did_small_cave_twice;
-      if is_small(&next) {
+      if is_small(next) {
          let count = path_stack.iter().filter(|x| *x == next).count();
          let limit = if did_small_cave_twice { 0 } else { 1 };
          if count > limit {
@@ -72,13 +96,9 @@ fn enumerate_paths(graph: &Graph, did_small_cave_twice: bool, path_stack: &mut V
    ret
 }
 
-// "How many paths through this cave system are there that visit small
-//  caves at most once?"
-fn solve(input: &str) -> usize {
-   let graph = parse_inputs(input);
-
+fn count_paths(graph: &Graph, allow_small_cave_twice: bool) -> usize {
    let mut path_stack = vec!["start".to_string()];
-   let paths = enumerate_paths(&graph, false, &mut path_stack);
+   let paths = enumerate_paths(graph, !allow_small_cave_twice, &mut path_stack);
    paths.len()
 }
 
@@ -86,66 +106,19 @@ fn solve(input: &str) -> usize {
 
 #[test]
 fn test_example() {
-   let input = "\
-start-A
-start-b
-A-c
-A-b
-b-d
-A-end
-b-end
-";
-   assert_eq!(solve(&input), 36);
+   let input = fixture!("example.txt");
+   assert_eq!(Day12::solve1(input), 10);
+   assert_eq!(Day12::solve2(input), 36);
 }
 #[test]
 fn test_example2() {
-   let input = "\
-dc-end
-HN-start
-start-kj
-dc-start
-dc-HN
-LN-dc
-HN-end
-kj-sa
-kj-HN
-kj-dc
-";
-   assert_eq!(solve(&input), 103);
+   let input = fixture!("example2.txt");
+   assert_eq!(Day12::solve1(input), 19);
+   assert_eq!(Day12::solve2(input), 103);
 }
 #[test]
 fn test_example3() {
-   let input = "\
-fs-end
-he-DX
-fs-he
-start-DX
-pj-DX
-end-zg
-zg-sl
-zg-pj
-pj-he
-RW-he
-fs-DX
-pj-RW
-zg-RW
-start-pj
-he-WI
-zg-he
-pj-fs
-start-RW
-";
-   assert_eq!(solve(&input), 3509);
-}
-
-fn main() {
-   let path = Path::new("day12-1/input.txt");
-   let mut file = match File::open(&path) {
-      Err(why) => panic!("File::open({}) -> Err({})", path.display(), why),
-      Ok(file) => file,
-   };
-   let mut input = String::new();
-   file.read_to_string(&mut input).unwrap();
-
-   println!("solve(input) -> {}", solve(&input));
+   let input = fixture!("example3.txt");
+   assert_eq!(Day12::solve1(input), 226);
+   assert_eq!(Day12::solve2(input), 3509);
 }
@@ -0,0 +1,140 @@
+use std::collections::HashSet;
+use aoc_common::{Grid, Point, Result, Solution, Source};
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+   type Input = (HashSet<Point>, Vec<Fold>);
+   type Answer1 = usize;
+   type Answer2 = String;
+   const DAY: u32 = 13;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse_inputs(input)
+   }
+   fn part1((dots, folds): &Self::Input) -> usize {
+      part1(dots, folds)
+   }
+   fn part2((dots, folds): &Self::Input) -> String {
+      part2(dots, folds)
+   }
+}
+
+// -
+
+pub struct Fold {
+   fold_along: String,
+   val: isize,
+}
+
+fn parse_inputs(input: &str) -> Result<(HashSet<Point>, Vec<Fold>)> {
+   let src = Source::new(input);
+   let (dot_lines, fold_lines) = src.split_once(input.trim(), "\n\n")?;
+   let dots: HashSet<_> = dot_lines.split('\n')
+      .map(|line| {
+         let (x,y) = src.split_once(line, ",")?;
+         Ok(Point{x:src.parse(x, "an x coordinate")?,
+                  y:src.parse(y, "a y coordinate")?})
+      }).collect::<Result<_>>()?;
+   let folds: Vec<_> = fold_lines.split('\n')
+      .map(|line| {
+         // "fold along y=7"
+         let (fold_along, val) = src.split_once(line, "=")?;
+         match fold_along {
+            "fold along x" | "fold along y" => (),
+            _ => return Err(src.error(fold_along, "\"fold along x\" or \"y\"")),
+         }
+         Ok(Fold{fold_along:fold_along.to_string(),
+                 val: src.parse(val, "a coordinate")?})
+      }).collect::<Result<_>>()?;
+   if folds.is_empty() {
+      return Err(src.error(fold_lines, "a fold"));
+   }
+   Ok((dots, folds))
+}
+
+fn fold_dots(dots: HashSet<Point>, fold: &Fold) -> HashSet<Point> {
+   match fold.fold_along.as_str() {
+      "fold along x" => {
+         dots.into_iter().map(|mut p| {
+            assert_ne!(p.x, fold.val);
+            if p.x > fold.val {
+               p.x = fold.val - (p.x - fold.val);
+            }
+            assert!(p.x >= 0, "{}", p.x);
+            p
+         }).collect()
+      },
+      "fold along y" => {
+         dots.into_iter().map(|mut p| {
+            assert_ne!(p.y, fold.val);
+            if p.y > fold.val { // e.g 8 > 7
+               p.y = fold.val - (p.y - fold.val); // -> 7 - (8 - 7)
+            }
+            assert!(p.y >= 0, "{}", p.y);
+            p
+         }).collect()
+      },
+      _ => panic!("{}", fold.fold_along),
+   }
+}
+
+// "How many dots are visible after completing just the first fold
+//  instruction on your transparent paper?"
+fn part1(dots: &HashSet<Point>, folds: &[Fold]) -> usize {
+   let mut dots = dots.clone();
+
+   for fold in &folds[..1] {
+      dots = fold_dots(dots, fold);
+   }
+   dots.len()
+}
+
+// "What code do you use to activate the infrared thermal imaging camera
+//  system?"
+// We can't read the letters, so return the drawing.
+fn part2(dots: &HashSet<Point>, folds: &[Fold]) -> String {
+   let mut dots = dots.clone();
+
+   for fold in folds.iter() {
+      dots = fold_dots(dots, fold);
+   }
+   //for (i,p) in dots.iter().enumerate() {
+   //   println!("{} {} {}", i, p.x, p.y);
+   //}
+   draw_dots(&dots)
+}
+
+// -
+
+fn draw_dots(dots: &HashSet<Point>) -> String {
+   let mut grid = Grid{rows: vec![vec!['.']]};
+   for dot in dots.iter() {
+      let mut size = grid.len();
+      while dot.x >= size.x {
+         grid.push_col(vec!['.';size.y as usize]);
+         size = grid.len();
+      }
+      while dot.y >= size.y {
+         grid.push_row(vec!['.';size.x as usize]);
+         size = grid.len();
+      }
+      *grid.at_mut(dot) = '#';
+   }
+   grid.to_string()
+}
+
+#[test]
+fn test_example() {
+   let input = fixture!("example.txt");
+   assert_eq!(Day13::solve1(input), 17);
+   assert_eq!(Day13::solve2(input), "\
+#####
+#...#
+#...#
+#...#
+#####
+");
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+use aoc_common::{Result, Solution, Source};
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+   type Input = (Vec<char>, Rules);
+   type Answer1 = usize;
+   type Answer2 = usize;
+   const DAY: u32 = 14;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse_inputs(input)
+   }
+   // "What do you get if you take the quantity of the most common element
+   //  and subtract the quantity of the least common element [after 10
+   //  steps]?"
+   fn part1((initial, rules): &Self::Input) -> usize {
+      solve(initial, rules, 10)
+   }
+   // "Apply 40 steps of pair insertion to the polymer template and find
+   //  the most and least common elements in the result."
+   fn part2((initial, rules): &Self::Input) -> usize {
+      solve(initial, rules, 40)
+   }
+}
+
+// -
+
+// Pair -> element inserted between them.
+pub type Rules = HashMap<(char,char),char>;
+
+fn parse_inputs(input: &str) -> Result<(Vec<char>, Rules)> {
+   let src = Source::new(input);
+   let (initial_s, rules_lines) = src.split_once(input.trim(), "\n\n")?;
+   let initial: Vec<char> = initial_s.chars().collect();
+   let rules: Rules = rules_lines.split("\n")
+      .map(|line| {
+         let (pair_s,res_s) = src.split_once(line, " -> ")?;
+         let pair: Vec<char> = pair_s.chars().collect();
+         let res: Vec<char> = res_s.chars().collect();
+         if pair.len() != 2 {
+            return Err(src.error(pair_s, "a pair of elements"));
+         }
+         if res.len() != 1 {
+            return Err(src.error(res_s, "a single element"));
+         }
+         Ok(((pair[0], pair[1]), res[0]))
+      }).collect::<Result<_>>()?;
+   Ok((initial, rules))
+}
+
+fn step(count_by_pair: HashMap<(char,char),usize>,
+      rules: &Rules) -> HashMap<(char,char),usize> {
+   let mut ret: HashMap<(char,char),usize> = HashMap::new();
+   for (pair,count) in count_by_pair {
+      let to_insert = rules[&pair];
+      [(pair.0, to_insert),
+       (to_insert, pair.1)].iter().for_each(|pair| {
+          let e = ret.entry(*pair).or_insert(0);
+          *e += count;
+       });
+   }
+   ret
+}
+
+fn solve(initial: &[char], rules: &Rules, steps: usize)
+      -> usize {
+   let mut state: HashMap<(char,char),usize> = HashMap::new();
+   initial.iter().zip(initial.iter().skip(1)).for_each(|pair| {
+      let e = state.entry((*pair.0, *pair.1)).or_insert(0);
+      *e += 1;
+   });
+
+   for _ in 0..steps {
+      //println!("{}", i);
+      state = step(state, rules);
+   }
+   let mut freq_by_char = HashMap::new();
+   for (pair,count) in state.into_iter() {
+      //println!("({},{}): {}", pair.0, pair.1, count);
+      [pair.0, pair.1].iter().for_each(|c| {
+         let e = freq_by_char.entry(*c).or_insert(0);
+         *e += count;
+      });
+   }
+   *freq_by_char.get_mut(initial.first().unwrap()).unwrap() += 1;
+   *freq_by_char.get_mut(initial.last().unwrap()).unwrap() += 1;
+   for (_,count) in freq_by_char.iter_mut() {
+      assert_eq!(*count % 2, 0);
+      *count /= 2;
+   }
+   let (_, max) = freq_by_char.iter().max_by_key(|(_,v)| *v).unwrap();
+   let (_, min) = freq_by_char.iter().min_by_key(|(_,v)| *v).unwrap();
+   max - min
+}
+
+// -
+
+#[test]
+fn test_example() {
+   let input = fixture!("example.txt");
+   //let (mut state, rules) = parse_inputs(&input);
+   //state = step(state, &rules);
+   //assert_eq!(state.iter().collect::<String>().as_str(), "NCNBCHB");
+   //state = step(state, &rules);
+   //assert_eq!(state.iter().collect::<String>().as_str(), "NBCCNBBBCBHCB");
+   //state = step(state, &rules);
+   //assert_eq!(state.iter().collect::<String>().as_str(), "NBBBCNCCNBBNBNBBCHBHHBCHB");
+   //state = step(state, &rules);
+   //assert_eq!(state.iter().collect::<String>().as_str(), "NBBNBNBBCCNBCNCCNBBNBBNBBBNBBNBBCBHCBHHNHCBBCBHCB");
+
+   assert_eq!(Day14::solve1(input), 1588);
+   assert_eq!(Day14::solve2(input), 2188189693529);
+}
@@ -0,0 +1,165 @@
+//use std::collections::HashSet;
+use log::info;
+use aoc_common::{Grid, Point, Result, Solution};
+#[cfg(test)]
+use aoc_common::fixture;
+use priority_queue::PriorityQueue;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+   type Input = Grid<isize>;
+   type Answer1 = isize;
+   type Answer2 = isize;
+   const DAY: u32 = 15;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse_inputs(input)
+   }
+   // "What is the lowest total risk of any path from the top left to the
+   //  bottom right?"
+   fn part1(grid: &Self::Input) -> isize {
+      solve(grid, 1)
+   }
+   // "Using the full map, what is the lowest total risk of any path from
+   //  the top left to the bottom right?"
+   fn part2(grid: &Self::Input) -> isize {
+      solve(grid, 5)
+   }
+}
+
+// -
+
+fn parse_inputs(input: &str) -> Result<Grid<isize>> {
+   Grid::from_digits(input)
+}
+
+//fn true_cost(grid: &Grid<isize>, p: &Point) -> isize {
+//   let size = grid.len();
+//   let mod_p = Point{x: p.x % size.x, y: p.y % size.y};
+//   let div_p = Point{x: p.x / size.x, y: p.y / size.y};
+//   if
+//   let subgrid_increase = p.x / 5 + p.y / 5;
+//   if 0 <= p.x &&
+
+fn fix_cost(cost: isize) -> isize {
+   assert!(cost - 9 < 10);
+   if cost >= 10 {
+      return cost - 9;
+   }
+   cost
+}
+
+fn inc_row_cost(row: &[isize], inc: isize) -> Vec<isize> {
+   row.iter().map(|x| fix_cost(x+inc)).collect()
+}
+
+fn scale_grid(part: Grid<isize>, scale: isize) -> Grid<isize> {
+   let size_before = part.len();
+   let mut rows = part.rows;
+   let mut new_rows_chunks: Vec<Vec<Vec<isize>>> = Vec::new();
+   for _ in 1..scale {
+      new_rows_chunks.push(Vec::new());
+   }
+   for row in rows.iter_mut() {
+      let mut big_row = Vec::with_capacity(row.len()*scale as usize);
+      for i in 0..scale {
+         let part = inc_row_cost(row, i);
+         big_row.extend_from_slice(part.as_slice());
+      }
+      for i in 1..scale {
+         new_rows_chunks[(i-1) as usize].push(inc_row_cost(&big_row, i));
+      }
+      *row = big_row;
+   }
+   new_rows_chunks.into_iter()
+      .for_each(|new_rows| rows.extend_from_slice(new_rows.as_slice()));
+
+   let ret = Grid{rows};
+   assert_eq!(ret.len().x, scale*size_before.x);
+   assert_eq!(ret.len().y, scale*size_before.y);
+   ret
+}
+
+fn initial_fill(path_cost_grid: &mut Grid<isize>, enter_cost_grid: &Grid<isize>) {
+   let size = path_cost_grid.len();
+   for i in 1..size.y {
+      let p = Point{x:0, y:i-1};
+      let n = Point{x:0, y:i};
+      let path_p_cost = *path_cost_grid.at(&p);
+      let enter_n_cost = enter_cost_grid.at(&n);
+      let new_path_n_cost = path_p_cost + *enter_n_cost;
+      *path_cost_grid.at_mut(&n) = new_path_n_cost;
+   }
+   for y in 0..size.y {
+      for x in 1..size.x {
+         let p = Point{x:x-1, y};
+         let n = Point{x  , y};
+         let path_p_cost = *path_cost_grid.at(&p);
+         let enter_n_cost = enter_cost_grid.at(&n);
+         let new_path_n_cost = path_p_cost + *enter_n_cost;
+         *path_cost_grid.at_mut(&n) = new_path_n_cost;
+      }
+   }
+   for y in 0..size.y {
+      for x in 0..size.x {
+         if x == 0 && y == 0 { continue; }
+         let n = Point{x  , y};
+         *path_cost_grid.at_mut(&n) += 1;
+      }
+   }
+}
+
+fn priority(path_cost_grid: &Grid<isize>, p: &Point) -> isize {
+   let size = path_cost_grid.len();
+   let cost = *path_cost_grid.at(p);
+   let estimated_remaining_cost = (size.x - p.x) + (size.y - p.y);
+   -(cost + estimated_remaining_cost)
+}
+
+fn solve(enter_cost_grid_part: &Grid<isize>, scale: isize) -> isize {
+   let enter_cost_grid = scale_grid(enter_cost_grid_part.clone(), scale);
+
+   let mut path_cost_grid = enter_cost_grid.clone();
+   path_cost_grid.fill(isize::MAX);
+   let mut cost_refreshed_set = PriorityQueue::new();
+
+   let start = Point{x:0,y:0};
+   *path_cost_grid.at_mut(&start) = 0;
+   initial_fill(&mut path_cost_grid, &enter_cost_grid);
+   cost_refreshed_set.push_increase(start, priority(&path_cost_grid, &start));
+
+   let mut i = 0;
+//   while let Some(p) = cost_refreshed_set.iter().next().cloned() {
+   while let Some((p,_)) = cost_refreshed_set.pop() {
+      i += 1;
+      if i % 100000 == 0 {
+         info!("{} popped", i);
+      }
+      //cost_refreshed_set.take(&p);
+
+      let path_p_cost = *path_cost_grid.at(&p);
+      enter_cost_grid.nearest4(&p).for_each(|n| {
+         let cur_path_n_cost = path_cost_grid.at_mut(&n);
+         let enter_n_cost = enter_cost_grid.at(&n);
+         let new_path_n_cost = path_p_cost + *enter_n_cost;
+         if *cur_path_n_cost > new_path_n_cost {
+            *cur_path_n_cost = new_path_n_cost;
+            //cost_refreshed_set.insert(n);
+            cost_refreshed_set.push_increase(n, priority(&path_cost_grid, &n));
+         }
+      });
+   }
+   let end = path_cost_grid.len().offset((-1,-1));
+   *path_cost_grid.at(&end)
+}
+
+// -
+
+#[test]
+fn test_example() {
+   let input = fixture!("example.txt");
+   assert_eq!(Day15::solve1(input), 40);
+   assert_eq!(Day15::solve2(input), 315);
+   assert!(Day15::load("").is_err());
+}
@@ -0,0 +1,114 @@
+// Random BITS transmissions, with their answers worked out as they're built.
+
+use aoc_common::Rng;
+
+use super::*;
+
+// -
+
+pub struct Case {
+   pub packet: ElfPacket,
+   pub hex: String,
+   pub version_sum: usize,
+   pub value: BigUint,
+}
+
+// Builds a tree of about `size` packets, with random framing.
+// Returns it with its version sum and value.
+fn packet(rng: &mut Rng, size: usize) -> (ElfPacket, usize, BigUint) {
+   let version = rng.below(8) as u8;
+
+   if size <= 1 {
+      // Mostly small, but now and then far wider than 64 bits.
+      let len = if rng.one_in(10) { rng.range(5..=40) } else { rng.range(1..=4) };
+      let nibbles: Vec<u8> = (0..len).map(|_| rng.below(16) as u8).collect();
+      let val = BigUint::from_radix_be(&nibbles, 16).unwrap();
+      let mut packet = ElfPacket::literal(version, val.clone());
+      if let Framing::Groups(groups) = packet.framing {
+         // Sometimes with leading zero groups.
+         let groups = groups + rng.below(3).saturating_sub(1) as usize;
+         packet = packet.with_framing(Framing::Groups(groups));
+      }
+      return (packet, version as usize, val);
+   }
+
+   // Comparisons need exactly two operands, so need room for them.
+   let ops: &[ElfPType] = if size >= 3 {
+      &[ElfPType::Sum, ElfPType::Product, ElfPType::Min, ElfPType::Max,
+        ElfPType::Greater, ElfPType::Less, ElfPType::Equal]
+   } else {
+      &[ElfPType::Sum, ElfPType::Product, ElfPType::Min, ElfPType::Max]
+   };
+   let op = *rng.pick(ops);
+   let num_subs = match op {
+      ElfPType::Greater | ElfPType::Less | ElfPType::Equal => 2,
+      _ => rng.range(1..=(size as i64 - 1).min(5)) as usize,
+   };
+   // Share out what's left of `size`, at least one each.
+   let mut sizes = vec![1; num_subs];
+   for _ in num_subs..(size - 1) {
+      let i = rng.below(num_subs as u64) as usize;
+      sizes[i] += 1;
+   }
+
+   let mut packets = Vec::new();
+   let mut version_sum = version as usize;
+   let mut vals = Vec::new();
+   for sub_size in sizes {
+      let (packet, sub_version_sum, val) = packet(rng, sub_size);
+      packets.push(packet);
+      version_sum += sub_version_sum;
+      vals.push(val);
+   }
+   let val = match op {
+      ElfPType::Sum => vals.iter().sum(),
+      ElfPType::Product => vals.iter().product(),
+      ElfPType::Min => vals.iter().min().unwrap().clone(),
+      ElfPType::Max => vals.iter().max().unwrap().clone(),
+      ElfPType::Greater => BigUint::from((vals[0] > vals[1]) as u8),
+      ElfPType::Less => BigUint::from((vals[0] < vals[1]) as u8),
+      ElfPType::Equal => BigUint::from((vals[0] == vals[1]) as u8),
+      ElfPType::Literal => unreachable!(),
+   };
+
+   // A bit count only fits if the sub-packets are short enough.
+   let sub_bits: usize = packets.iter().map(|p| p.bit_len()).sum();
+   let framing = if rng.one_in(2) && sub_bits < 1 << 15 {
+      Framing::BitCount
+   } else {
+      Framing::PacketCount
+   };
+   let packet = ElfPacket::operator(version, op, packets).with_framing(framing);
+   (packet, version_sum, val)
+}
+
+pub fn case(rng: &mut Rng, size: usize) -> Case {
+   let (packet, version_sum, value) = packet(rng, size.max(1));
+   let hex = packet.encode().unwrap();
+   Case{packet, hex, version_sum, value}
+}
+
+// A transmission of about `size` packets.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+   case(rng, size).hex + "\n"
+}
+
+// -
+
+#[test]
+fn test_gen() {
+   for seed in 0..1000 {
+      let mut rng = Rng::new(seed);
+      let case = case(&mut rng, 1 + seed as usize % 40);
+      let packet = Day16::parse(&case.hex).unwrap();
+      assert_eq!(packet, case.packet);
+      assert_eq!(Day16::part1(&packet), case.version_sum, "{}", case.hex);
+      assert_eq!(Day16::part2(&packet), case.value, "{}", case.hex);
+   }
+
+   // Too big for some bit counts.
+   for seed in 0..5 {
+      let case = case(&mut Rng::new(seed), 2000 + seed as usize * 1000);
+      assert_eq!(Day16::parse(&case.hex).unwrap(), case.packet);
+   }
+}
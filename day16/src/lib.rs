@@ -0,0 +1,914 @@
+use std::cmp;
+use std::fmt;
+use bitreader::BitReader;
+use num_bigint::BigUint;
+use log::{debug, trace};
+use aoc_common::{ParseError, Result, Solution, Source};
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub mod gen;
+pub mod stream;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+   type Input = ElfPacket;
+   type Answer1 = usize;
+   type Answer2 = BigUint;
+   const DAY: u32 = 16;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      let src = Source::new(input);
+      let hex = input.trim();
+      let bytes = parse_inputs(&src, hex)?;
+      ElfPacket::decode(&bytes).map_err(|e| e.at_hex(&src, hex))
+   }
+   // "Decode the structure of your hexadecimal-encoded BITS transmission;
+   //  what do you get if you add up the version numbers in all packets?"
+   fn part1(root: &Self::Input) -> usize {
+      root.version_sum()
+   }
+   // "What do you get if you evaluate the expression represented by your
+   //  hexadecimal-encoded BITS transmission?"
+   fn part2(root: &Self::Input) -> BigUint {
+      debug!("{}", root.infix());
+      trace!("\n{}", root.tree());
+      root.eval()
+   }
+}
+
+// -
+
+fn parse_inputs(src: &Source, chars: &str) -> Result<Vec<u8>> {
+   let mut bytes = vec![0u8; chars.len().div_ceil(2)];
+   for ic in 0..chars.len() {
+      let ib = ic / 2;
+      let is_low = ic & 1 == 1;
+      let mut val = src.digit(&chars[ic..], 16)?;
+      if !is_low {
+         val <<= 4;
+      }
+      bytes[ib] |= val as u8;
+   }
+   Ok(bytes)
+}
+
+// Everything that can be wrong with a transmission, at the bit offset where
+// it went wrong.
+#[derive(PartialEq,Eq,Clone,Debug)]
+pub enum BitsError {
+   // Wanted `bits` more bits, but the transmission ended.
+   Truncated{at: u64, bits: u8},
+   // A bit-count operator's sub-packets ran past `end`, to `reached`.
+   Overrun{at: u64, end: u64, reached: u64},
+   // Comparisons take exactly two sub-packets.
+   Arity{at: u64, ptype: ElfPType, count: usize},
+   // Min and max of nothing.
+   Empty{at: u64, ptype: ElfPType},
+   // Nested past MAX_DEPTH.
+   TooDeep{at: u64},
+   // A 1 bit in the padding after the outermost packet.
+   TrailingData{at: u64},
+   // Streamed input that isn't a hex digit (or trailing whitespace).
+   BadHex{at: u64, found: char},
+   // Streamed input that couldn't be read.
+   Io{at: u64, message: String},
+}
+
+// Far deeper than any real transmission, but shallow enough not to run out
+// of stack.
+const MAX_DEPTH: usize = 256;
+
+impl BitsError {
+   pub fn at(&self) -> u64 {
+      match *self {
+         BitsError::Truncated{at, ..} | BitsError::Overrun{at, ..} |
+         BitsError::Arity{at, ..} | BitsError::Empty{at, ..} |
+         BitsError::TooDeep{at} |
+         BitsError::TrailingData{at} | BitsError::BadHex{at, ..} |
+         BitsError::Io{at, ..} => at,
+      }
+   }
+   fn expected_found(&self) -> (String, String) {
+      match self {
+         BitsError::Truncated{bits, ..} =>
+            (format!("{} more bits", bits), "end of transmission".to_string()),
+         BitsError::Overrun{end, reached, ..} =>
+            (format!("sub-packets to end at bit {}", end),
+             format!("bit {}", reached)),
+         BitsError::Arity{ptype, count, ..} =>
+            (format!("{} sub-packets for {}", ptype.op().min_count,
+                     ptype.name()), count.to_string()),
+         BitsError::Empty{ptype, ..} =>
+            (format!("sub-packets for {}", ptype.name()), "none".to_string()),
+         BitsError::TooDeep{..} =>
+            (format!("at most {} nested packets", MAX_DEPTH),
+             "more".to_string()),
+         BitsError::TrailingData{..} =>
+            ("zero padding".to_string(), "a 1 bit".to_string()),
+         BitsError::BadHex{found, ..} =>
+            ("a hex digit".to_string(), format!("{:?}", found)),
+         BitsError::Io{message, ..} =>
+            ("more input".to_string(), message.clone()),
+      }
+   }
+   // Located at the hex digit holding the bad bit.
+   fn at_hex(&self, src: &Source, hex: &str) -> ParseError {
+      let digit = cmp::min((self.at() / 4) as usize, hex.len());
+      let (line, col) = src.locate(&hex[digit..]);
+      let (expected, found) = self.expected_found();
+      ParseError{day: None, line, col,
+                 expected: format!("{} (at bit {})", expected, self.at()),
+                 found}
+   }
+}
+impl fmt::Display for BitsError {
+   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      let (expected, found) = self.expected_found();
+      write!(f, "bit {}: expected {}, found {}", self.at(), expected, found)
+   }
+}
+impl std::error::Error for BitsError {}
+
+type BitsResult<T> = std::result::Result<T, BitsError>;
+
+struct Bits<'a> {
+   reader: BitReader<'a>,
+}
+impl<'a> Bits<'a> {
+   fn position(&self) -> u64 {
+      self.reader.position()
+   }
+   fn read(&mut self, bits: u8) -> BitsResult<u32> {
+      let at = self.position();
+      self.reader.read_u32(bits).map_err(|_| BitsError::Truncated{at, bits})
+   }
+}
+
+// Bits, most significant first.
+struct BitWriter {
+   bytes: Vec<u8>,
+   len: usize,
+}
+impl BitWriter {
+   fn new() -> BitWriter {
+      BitWriter{bytes: Vec::new(), len: 0}
+   }
+   fn push(&mut self, bit: bool) {
+      if self.len.is_multiple_of(8) {
+         self.bytes.push(0);
+      }
+      if bit {
+         *self.bytes.last_mut().unwrap() |= 0x80 >> (self.len % 8);
+      }
+      self.len += 1;
+   }
+   // The low `bits` bits of `val`.
+   fn write(&mut self, val: usize, bits: usize) {
+      for i in (0..bits).rev() {
+         self.push(val >> i & 1 == 1);
+      }
+   }
+   fn append(&mut self, other: &BitWriter) {
+      for i in 0..other.len {
+         self.push(other.bytes[i / 8] & (0x80 >> (i % 8)) != 0);
+      }
+   }
+   fn to_hex(&self) -> String {
+      self.bytes.iter().map(|b| format!("{:02X}", b)).collect()
+   }
+}
+
+#[derive(PartialEq,Eq,Clone,Debug)]
+struct ElfHeader {
+   version: u8,
+   ptype: ElfPType,
+}
+impl ElfHeader {
+   // Every 3-bit type id means something.
+   fn ptype(id: u32) -> ElfPType {
+      OPS[id as usize].ptype
+   }
+   fn from(bits: &mut Bits) -> BitsResult<ElfHeader> {
+      Ok(ElfHeader{
+         version: bits.read(3)? as u8,
+         ptype: ElfHeader::ptype(bits.read(3)?),
+      })
+   }
+}
+
+#[derive(PartialEq,Eq,Clone,Copy,Debug)]
+pub enum ElfPType {
+   Sum = 0,
+   Product = 1,
+   Min = 2,
+   Max = 3,
+   Literal = 4,
+   Greater = 5,
+   Less = 6,
+   Equal = 7,
+}
+
+#[derive(PartialEq,Eq,Clone,Debug)]
+enum ElfData {
+   // Any number of groups, so any size.
+   Literal(BigUint),
+   Operator(Vec<ElfPacket>),
+}
+
+// How a packet is laid out in bits. Doesn't change what it means, but
+// keeping it lets a decoded packet encode back to the same bits.
+#[derive(PartialEq,Eq,Clone,Copy,Debug)]
+pub enum Framing {
+   // A literal, in this many 4-bit groups. (Leading zero groups are fine.)
+   Groups(usize),
+   // Operator length type 0: the sub-packets' total length in bits.
+   BitCount,
+   // Operator length type 1: the number of sub-packets.
+   PacketCount,
+}
+
+#[derive(PartialEq,Eq,Clone,Debug)]
+pub struct ElfPacket {
+   header: ElfHeader,
+   data: ElfData,
+   framing: Framing,
+}
+impl ElfPacket {
+   // In as few groups as it takes.
+   pub fn literal(version: u8, val: impl Into<BigUint>) -> ElfPacket {
+      let val = val.into();
+      let groups = cmp::max(1, val.bits().div_ceil(4)) as usize;
+      ElfPacket{
+         header: ElfHeader{version, ptype: ElfPType::Literal},
+         data: ElfData::Literal(val),
+         framing: Framing::Groups(groups),
+      }
+   }
+   // Framed by bit count. Panics on a sub-packet count that `decode` would
+   // reject, so `eval` never sees one.
+   pub fn operator(version: u8, ptype: ElfPType, packets: Vec<ElfPacket>)
+         -> ElfPacket {
+      assert_ne!(ptype, ElfPType::Literal);
+      if let Err(e) = ptype.check_count(0, packets.len()) {
+         let (expected, found) = e.expected_found();
+         panic!("Expected {}, found {}.", expected, found);
+      }
+      ElfPacket{
+         header: ElfHeader{version, ptype},
+         data: ElfData::Operator(packets),
+         framing: Framing::BitCount,
+      }
+   }
+   pub fn with_framing(mut self, framing: Framing) -> ElfPacket {
+      self.framing = framing;
+      self
+   }
+
+   // The outermost packet of a transmission. Anything after it must be
+   // zero padding.
+   pub fn decode(bytes: &[u8]) -> BitsResult<ElfPacket> {
+      let mut bits = Bits{reader: BitReader::new(bytes)};
+      let packet = ElfPacket::from(&mut bits, 0)?;
+      while bits.reader.remaining() > 0 {
+         let at = bits.position();
+         if bits.read(1)? != 0 {
+            return Err(BitsError::TrailingData{at});
+         }
+      }
+      Ok(packet)
+   }
+
+   fn from(bits: &mut Bits, depth: usize) -> BitsResult<ElfPacket> {
+      let at = bits.position();
+      if depth > MAX_DEPTH {
+         return Err(BitsError::TooDeep{at});
+      }
+      let header = ElfHeader::from(bits)?;
+      let mut framing = Framing::BitCount;
+      let data = match header.ptype {
+         ElfPType::Literal => {
+            let mut val = BigUint::default();
+            let mut groups = 0;
+            loop {
+               let group = bits.read(5)?;
+               let has_more = (group & 0b10000) != 0;
+               groups += 1;
+               val = val << 4u8 | BigUint::from(group & 0b01111);
+               if !has_more { break; }
+            }
+            framing = Framing::Groups(groups);
+            ElfData::Literal(val)
+         },
+         _ => {
+            // Operator
+            let length_type_id = bits.read(1)?;
+            let packets = if length_type_id == 0 {
+               let num_bits = bits.read(15)?;
+               let end = bits.position() + num_bits as u64;
+               let mut packets = Vec::new();
+               while bits.position() < end {
+                  packets.push(ElfPacket::from(bits, depth + 1)?);
+                  let reached = bits.position();
+                  if reached > end {
+                     return Err(BitsError::Overrun{at, end, reached});
+                  }
+               }
+               packets
+            } else {
+               framing = Framing::PacketCount;
+               let num_packets = bits.read(11)?;
+               (0..num_packets).map(|_| {
+                  ElfPacket::from(bits, depth + 1)
+               }).collect::<BitsResult<Vec<_>>>()?
+            };
+            header.ptype.check_count(at, packets.len())?;
+            ElfData::Operator(packets)
+         },
+      };
+      Ok(ElfPacket{
+         header,
+         data,
+         framing,
+      })
+   }
+
+   // Back to a hex transmission, zero-padded to whole bytes.
+   pub fn encode(&self) -> std::result::Result<String, String> {
+      let mut writer = BitWriter::new();
+      self.write(&mut writer)?;
+      Ok(writer.to_hex())
+   }
+
+   fn write(&self, w: &mut BitWriter) -> std::result::Result<(), String> {
+      if self.header.version >= 1 << 3 {
+         return Err(format!("version {} won't fit in 3 bits",
+                            self.header.version));
+      }
+      w.write(self.header.version as usize, 3);
+      w.write(self.header.ptype as usize, 3);
+      match (&self.data, self.framing) {
+         (ElfData::Literal(val), Framing::Groups(groups)) => {
+            let nibbles = val.to_radix_be(16);
+            if groups == 0 || nibbles.len() > groups {
+               return Err(format!("{} doesn't fit in {} groups",
+                                  val, groups));
+            }
+            for i in (0..groups).rev() {
+               let group = match nibbles.len().checked_sub(i + 1) {
+                  Some(n) => nibbles[n] as usize,
+                  None => 0,
+               };
+               let has_more = (i != 0) as usize;
+               w.write(has_more << 4 | group, 5);
+            }
+         },
+         (ElfData::Operator(packets), Framing::BitCount) => {
+            let mut sub = BitWriter::new();
+            for p in packets.iter() {
+               p.write(&mut sub)?;
+            }
+            if sub.len >= 1 << 15 {
+               return Err(format!("{} bits of sub-packets won't fit in a \
+                                   15-bit length", sub.len));
+            }
+            w.write(0, 1);
+            w.write(sub.len, 15);
+            w.append(&sub);
+         },
+         (ElfData::Operator(packets), Framing::PacketCount) => {
+            if packets.len() >= 1 << 11 {
+               return Err(format!("{} sub-packets won't fit in an 11-bit \
+                                   count", packets.len()));
+            }
+            w.write(1, 1);
+            w.write(packets.len(), 11);
+            for p in packets.iter() {
+               p.write(w)?;
+            }
+         },
+         (_, framing) => {
+            return Err(format!("{:?} can't frame a {:?} packet",
+                               framing, self.header.ptype));
+         },
+      }
+      Ok(())
+   }
+
+   fn version_sum(&self) -> usize {
+      let mut sum = self.header.version as usize;
+      if let ElfData::Operator(packets) = &self.data {
+         sum += packets.iter().map(|p| p.version_sum()).sum::<usize>();
+      }
+      sum
+   }
+
+   fn eval(&self) -> BigUint {
+      match &self.data {
+         ElfData::Literal(val) => val.clone(),
+         ElfData::Operator(packets) => {
+            let op = self.header.ptype.op();
+            packets.iter().map(|p| p.eval()).reduce(op.fold)
+               .unwrap_or_else(|| op.identity().unwrap())
+         },
+      }
+   }
+
+   // An equivalent packet, as small as it gets: nested sums, products,
+   // mins and maxes flattened, literal operands folded together, and
+   // operators of one operand replaced by it. Only literals are versioned
+   // (by the packet they replace), and framings are the shortest.
+   pub fn optimize(&self) -> ElfPacket {
+      let (version, ptype) = (self.header.version, self.header.ptype);
+      let packets = match &self.data {
+         ElfData::Literal(val) => {
+            return ElfPacket::literal(version, val.clone());
+         },
+         ElfData::Operator(packets) => packets,
+      };
+      let op = ptype.op();
+      let mut operands = Vec::new();
+      for sub in packets.iter().map(ElfPacket::optimize) {
+         match sub.data {
+            ElfData::Operator(subs)
+                  if op.flattens && sub.header.ptype == ptype => {
+               operands.extend(subs);
+            },
+            data => operands.push(ElfPacket{data, ..sub}),
+         }
+      }
+
+      let value = |p: &ElfPacket| match &p.data {
+         ElfData::Literal(val) => Some(val.clone()),
+         ElfData::Operator(_) => None,
+      };
+      if operands.iter().all(|p| value(p).is_some()) {
+         let folded = operands.iter().map(|p| value(p).unwrap())
+            .reduce(op.fold).or_else(|| op.identity());
+         if let Some(val) = folded {
+            return ElfPacket::literal(version, val);
+         }
+      } else if op.flattens {
+         // Order doesn't matter, so fold every literal into one, up front.
+         let (literals, mut rest): (Vec<_>, Vec<_>) = operands.into_iter()
+            .partition(|p| value(p).is_some());
+         let folded = literals.iter().map(|p| value(p).unwrap())
+            .reduce(op.fold);
+         let identity = op.identity();
+         if let Some(val) = folded.filter(|v| Some(v) != identity.as_ref()) {
+            rest.insert(0, ElfPacket::literal(version, val));
+         }
+         operands = rest;
+      }
+      if op.flattens && operands.len() == 1 {
+         return operands.pop().unwrap();
+      }
+      let framing = if operands.len() < 1 << 11 {
+         Framing::PacketCount
+      } else {
+         Framing::BitCount
+      };
+      ElfPacket::operator(version, ptype, operands).with_framing(framing)
+   }
+
+   // How many bits `write` takes.
+   fn bit_len(&self) -> usize {
+      6 + match (&self.data, self.framing) {
+         (_, Framing::Groups(groups)) => 5 * groups,
+         (ElfData::Operator(packets), framing) => {
+            let len_bits = if framing == Framing::BitCount { 15 } else { 11 };
+            1 + len_bits + packets.iter().map(|p| p.bit_len()).sum::<usize>()
+         },
+         (ElfData::Literal(_), _) => 0,
+      }
+   }
+
+   // One packet per line, indented by depth:
+   //    @0 v1 type 6 less (bit count)
+   //       @22 v6 type 4 literal 10
+   pub fn tree(&self) -> Tree<'_> {
+      Tree(self)
+   }
+   // "(> (min 3 (* 5 7)) 2)"
+   pub fn sexpr(&self) -> SExpr<'_> {
+      SExpr(self)
+   }
+   // "min(3, 5*7) > 2"
+   pub fn infix(&self) -> Infix<'_> {
+      Infix(self)
+   }
+}
+
+// -
+// Packet types
+
+// What each packet type means, indexed by type id.
+struct Op {
+   ptype: ElfPType,
+   name: &'static str,
+   symbol: &'static str,
+   // For infix: higher binds tighter.
+   precedence: u8,
+   // How many sub-packets it takes.
+   min_count: usize,
+   max_count: Option<usize>,
+   // Its value with no sub-packets, if it can have none.
+   identity: Option<u8>,
+   // Associative and commutative: nested ones flatten, and operands can
+   // be folded in any order.
+   flattens: bool,
+   // Combines the value so far with the next operand.
+   fold: fn(BigUint, BigUint) -> BigUint,
+}
+
+fn bool(b: bool) -> BigUint {
+   BigUint::from(b as u8)
+}
+
+const OPS: [Op; 8] = [
+   Op{ptype: ElfPType::Sum, name: "sum", symbol: "+", precedence: 2,
+      min_count: 0, max_count: None, identity: Some(0), flattens: true,
+      fold: |a, b| a + b},
+   Op{ptype: ElfPType::Product, name: "product", symbol: "*", precedence: 3,
+      min_count: 0, max_count: None, identity: Some(1), flattens: true,
+      fold: |a, b| a * b},
+   Op{ptype: ElfPType::Min, name: "min", symbol: "min", precedence: 4,
+      min_count: 1, max_count: None, identity: None, flattens: true,
+      fold: cmp::min},
+   Op{ptype: ElfPType::Max, name: "max", symbol: "max", precedence: 4,
+      min_count: 1, max_count: None, identity: None, flattens: true,
+      fold: cmp::max},
+   Op{ptype: ElfPType::Literal, name: "literal", symbol: "literal",
+      precedence: 4, min_count: 0, max_count: Some(0), identity: None,
+      flattens: false, fold: |_, _| unreachable!()},
+   Op{ptype: ElfPType::Greater, name: "greater", symbol: ">", precedence: 1,
+      min_count: 2, max_count: Some(2), identity: None, flattens: false,
+      fold: |a, b| bool(a > b)},
+   Op{ptype: ElfPType::Less, name: "less", symbol: "<", precedence: 1,
+      min_count: 2, max_count: Some(2), identity: None, flattens: false,
+      fold: |a, b| bool(a < b)},
+   Op{ptype: ElfPType::Equal, name: "equal", symbol: "==", precedence: 1,
+      min_count: 2, max_count: Some(2), identity: None, flattens: false,
+      fold: |a, b| bool(a == b)},
+];
+
+impl Op {
+   fn identity(&self) -> Option<BigUint> {
+      self.identity.map(BigUint::from)
+   }
+}
+
+impl ElfPType {
+   fn op(self) -> &'static Op {
+      &OPS[self as usize]
+   }
+   fn name(&self) -> &'static str {
+      self.op().name
+   }
+   fn symbol(&self) -> &'static str {
+      self.op().symbol
+   }
+   // An operator with `count` sub-packets, at bit `at`.
+   fn check_count(self, at: u64, count: usize) -> BitsResult<()> {
+      let op = self.op();
+      if count == 0 && op.min_count > 0 && op.max_count.is_none() {
+         return Err(BitsError::Empty{at, ptype: self});
+      }
+      if count < op.min_count || op.max_count.is_some_and(|max| count > max) {
+         return Err(BitsError::Arity{at, ptype: self, count});
+      }
+      Ok(())
+   }
+}
+
+// -
+// Renderers
+
+pub struct Tree<'a>(&'a ElfPacket);
+
+fn write_tree(f: &mut fmt::Formatter, p: &ElfPacket, depth: usize,
+              offset: usize) -> fmt::Result {
+   write!(f, "{:w$}@{} v{} type {} {}", "", offset, p.header.version,
+          p.header.ptype as u8, p.header.ptype.name(), w = 3 * depth)?;
+   match (&p.data, p.framing) {
+      (ElfData::Literal(val), Framing::Groups(groups)) => {
+         write!(f, " {}", val)?;
+         let min_groups = ElfPacket::literal(0, val.clone()).framing;
+         if min_groups != Framing::Groups(groups) {
+            write!(f, " ({} groups)", groups)?;
+         }
+         writeln!(f)
+      },
+      (ElfData::Operator(packets), framing) => {
+         let (label, len_bits) = if framing == Framing::BitCount {
+            ("bit count", 15)
+         } else {
+            ("packet count", 11)
+         };
+         writeln!(f, " ({})", label)?;
+         let mut offset = offset + 6 + 1 + len_bits;
+         for sub in packets.iter() {
+            write_tree(f, sub, depth + 1, offset)?;
+            offset += sub.bit_len();
+         }
+         Ok(())
+      },
+      _ => writeln!(f, " ({:?}?)", p.framing),
+   }
+}
+
+impl fmt::Display for Tree<'_> {
+   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      write_tree(f, self.0, 0, 0)
+   }
+}
+
+pub struct SExpr<'a>(&'a ElfPacket);
+
+impl fmt::Display for SExpr<'_> {
+   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      match &self.0.data {
+         ElfData::Literal(val) => write!(f, "{}", val),
+         ElfData::Operator(packets) => {
+            write!(f, "({}", self.0.header.ptype.symbol())?;
+            for sub in packets.iter() {
+               write!(f, " {}", sub.sexpr())?;
+            }
+            write!(f, ")")
+         },
+      }
+   }
+}
+
+pub struct Infix<'a>(&'a ElfPacket);
+
+impl Infix<'_> {
+   // Higher binds tighter.
+   fn precedence(&self) -> u8 {
+      match (&self.0.data, self.0.header.ptype) {
+         (ElfData::Operator(packets), ElfPType::Sum | ElfPType::Product)
+               if packets.len() == 1 => packets[0].infix().precedence(),
+         // Written as its identity, which is just a number.
+         (ElfData::Operator(packets), ElfPType::Sum | ElfPType::Product)
+               if packets.is_empty() => ElfPType::Literal.op().precedence,
+         (_, ptype) => ptype.op().precedence,
+      }
+   }
+}
+
+impl fmt::Display for Infix<'_> {
+   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      let packets = match &self.0.data {
+         ElfData::Literal(val) => return write!(f, "{}", val),
+         ElfData::Operator(packets) => packets,
+      };
+      let ptype = self.0.header.ptype;
+      if packets.is_empty() {
+         if let Some(identity) = ptype.op().identity {
+            return write!(f, "{}", identity);
+         }
+      }
+      if let ElfPType::Min | ElfPType::Max = ptype {
+         write!(f, "{}(", ptype.name())?;
+         for (i, sub) in packets.iter().enumerate() {
+            let sep = if i == 0 { "" } else { ", " };
+            write!(f, "{}{}", sep, sub.infix())?;
+         }
+         return write!(f, ")");
+      }
+      // Comparisons don't chain, so their operands need to bind tighter.
+      let prec = self.precedence();
+      let min_prec = if prec == 1 { 2 } else { prec };
+      let sep = match ptype {
+         ElfPType::Product => "*".to_string(),
+         _ => format!(" {} ", ptype.symbol()),
+      };
+      for (i, sub) in packets.iter().enumerate() {
+         if i != 0 {
+            write!(f, "{}", sep)?;
+         }
+         let sub = sub.infix();
+         if sub.precedence() < min_prec {
+            write!(f, "({})", sub)?;
+         } else {
+            write!(f, "{}", sub)?;
+         }
+      }
+      Ok(())
+   }
+}
+
+// -
+
+#[test]
+fn test_example() {
+   {
+      let packet = Day16::parse(fixture!("literal.txt")).unwrap();
+      assert_eq!(packet.header.version, 6);
+      assert_eq!(packet.header.ptype, ElfPType::Literal);
+      match packet.data {
+         ElfData::Literal(val) => {
+            assert_eq!(val, 2021u32.into());
+         },
+         _ => panic!(),
+      }
+   }
+   for (hex, answer) in fixture::cases(fixture!("part1.txt")) {
+      assert_eq!(Day16::solve1(hex).to_string(), answer, "{}", hex);
+   }
+   for (hex, answer) in fixture::cases(fixture!("part2.txt")) {
+      assert_eq!(Day16::solve2(hex).to_string(), answer, "{}", hex);
+   }
+}
+
+#[test]
+fn test_encode() {
+   // Every example decodes and encodes back to itself.
+   let examples = fixture::cases(fixture!("part1.txt")).into_iter()
+      .chain(fixture::cases(fixture!("part2.txt")))
+      .map(|(hex, _)| hex)
+      .chain([fixture!("literal.txt").trim()]);
+   for hex in examples {
+      let packet = Day16::parse(hex).unwrap();
+      assert_eq!(packet.encode().unwrap(), hex);
+   }
+
+   let a = ElfPacket::literal(1, 10u32);
+   let b = ElfPacket::literal(2, 0x123u32).with_framing(Framing::Groups(5));
+   let sum = ElfPacket::operator(3, ElfPType::Sum, vec![a.clone(), b]);
+   for framing in [Framing::BitCount, Framing::PacketCount] {
+      let packet = sum.clone().with_framing(framing);
+      let hex = packet.encode().unwrap();
+      let decoded = Day16::parse(&hex).unwrap();
+      assert_eq!(decoded, packet);
+      assert_eq!(Day16::part2(&decoded), BigUint::from(10u32 + 0x123));
+   }
+   // "D2FE28" is 2021 in 3 groups.
+   assert_eq!(ElfPacket::literal(6, 2021u32).encode().unwrap(), "D2FE28");
+
+   assert!(ElfPacket::literal(0, 0x123u32).with_framing(Framing::Groups(2))
+              .encode().is_err());
+   assert!(a.with_framing(Framing::BitCount).encode().is_err());
+   assert!(ElfPacket::literal(8, 1u32).encode().is_err());
+   let deep = ElfPacket::operator(1, ElfPType::Sum,
+                                  vec![ElfPacket::literal(9, 1u32)]);
+   assert!(deep.encode().is_err());
+}
+
+#[test]
+fn test_render() {
+   let packet = Day16::parse("38006F45291200").unwrap();
+   assert_eq!(packet.tree().to_string(), "\
+@0 v1 type 6 less (bit count)
+   @22 v6 type 4 literal 10
+   @33 v2 type 4 literal 20
+");
+   assert_eq!(packet.sexpr().to_string(), "(< 10 20)");
+   assert_eq!(packet.infix().to_string(), "10 < 20");
+
+   let packet = Day16::parse("9C0141080250320F1802104A08").unwrap();
+   assert_eq!(packet.sexpr().to_string(), "(== (+ 1 3) (* 2 2))");
+   assert_eq!(packet.infix().to_string(), "1 + 3 == 2*2");
+
+   // "min(3, 5*7) > 2"
+   let lit = |val: u32| ElfPacket::literal(0, val);
+   let product = ElfPacket::operator(0, ElfPType::Product, vec![lit(5), lit(7)]);
+   let min = ElfPacket::operator(0, ElfPType::Min, vec![lit(3), product]);
+   let packet = ElfPacket::operator(0, ElfPType::Greater, vec![min, lit(2)]);
+   assert_eq!(packet.infix().to_string(), "min(3, 5*7) > 2");
+   assert_eq!(packet.sexpr().to_string(), "(> (min 3 (* 5 7)) 2)");
+
+   let sum = ElfPacket::operator(0, ElfPType::Sum, vec![lit(1), lit(2)]);
+   let packet = ElfPacket::operator(0, ElfPType::Product,
+                                    vec![sum.clone(), lit(3)]);
+   assert_eq!(packet.infix().to_string(), "(1 + 2)*3");
+   let packet = ElfPacket::operator(0, ElfPType::Equal, vec![
+      ElfPacket::operator(0, ElfPType::Less, vec![lit(1), lit(2)]),
+      ElfPacket::operator(0, ElfPType::Sum, vec![lit(1)]),
+   ]);
+   assert_eq!(packet.infix().to_string(), "(1 < 2) == 1");
+
+   // Nothing to add or multiply is 0 or 1.
+   let empty = |ptype| ElfPacket::operator(0, ptype, vec![]);
+   assert_eq!(empty(ElfPType::Sum).infix().to_string(), "0");
+   assert_eq!(empty(ElfPType::Product).infix().to_string(), "1");
+   let packet = ElfPacket::operator(0, ElfPType::Product, vec![
+      lit(2), empty(ElfPType::Sum), empty(ElfPType::Product),
+   ]);
+   assert_eq!(packet.infix().to_string(), "2*0*1");
+   let packet = ElfPacket::operator(0, ElfPType::Less, vec![
+      empty(ElfPType::Sum), sum.clone(),
+   ]);
+   assert_eq!(packet.infix().to_string(), "0 < 1 + 2");
+
+   let packet = lit(1).with_framing(Framing::Groups(3));
+   assert_eq!(packet.tree().to_string(), "@0 v0 type 4 literal 1 (3 groups)\n");
+}
+
+#[test]
+fn test_decode_errors() {
+   // "110 100 10111 11110 00101 000" with spaces for readability.
+   fn bytes(bits: &str) -> Vec<u8> {
+      let mut w = BitWriter::new();
+      for c in bits.chars().filter(|c| !c.is_whitespace()) {
+         w.push(c == '1');
+      }
+      w.bytes
+   }
+   let decode = |bits: &str| ElfPacket::decode(&bytes(bits)).unwrap_err();
+
+   assert_eq!(decode("110 100 10111 11110"),
+              BitsError::Truncated{at: 16, bits: 5});
+   // A 10-bit operator holding an 11-bit literal.
+   assert_eq!(decode("000 000 0 000000000001010 000 100 00001"),
+              BitsError::Overrun{at: 0, end: 32, reached: 33});
+   assert_eq!(decode("000 110 1 00000000001 000 100 00001"),
+              BitsError::Arity{at: 0, ptype: ElfPType::Less, count: 1});
+   assert_eq!(decode("000 000 1 00000000001 000 010 1 00000000000"),
+              BitsError::Empty{at: 18, ptype: ElfPType::Min});
+   assert_eq!(decode("000 100 00001 1"), BitsError::TrailingData{at: 11});
+   let nested = "000 000 1 00000000001 ".repeat(MAX_DEPTH + 1);
+   assert_eq!(decode(&format!("{} 000 100 00001", nested)),
+              BitsError::TooDeep{at: 18 * (MAX_DEPTH as u64 + 1)});
+
+   let e = Day16::parse("D2FE\n").unwrap_err();
+   assert_eq!(e.to_string(), "line 1, column 5: expected 5 more bits \
+                              (at bit 16), found end of transmission");
+   let e = Day16::parse("D2FE29").unwrap_err();
+   assert_eq!((e.col, e.found.as_str()), (6, "a 1 bit"));
+
+   // Garbage never panics.
+   let mut rng = aoc_common::Rng::new(16);
+   for _ in 0..2000 {
+      let len = rng.below(40) as usize;
+      let garbage: Vec<u8> = (0..len).map(|_| rng.below(256) as u8).collect();
+      if let Ok(packet) = ElfPacket::decode(&garbage) {
+         packet.version_sum();
+      }
+   }
+}
+
+#[test]
+#[should_panic(expected = "Expected sub-packets for min, found none.")]
+fn test_operator_empty() {
+   ElfPacket::operator(0, ElfPType::Min, vec![]);
+}
+
+#[test]
+#[should_panic(expected = "Expected 2 sub-packets for greater, found 3.")]
+fn test_operator_arity() {
+   let lit = |val: u64| ElfPacket::literal(0, val);
+   ElfPacket::operator(0, ElfPType::Greater, vec![lit(1), lit(2), lit(3)]);
+}
+
+#[test]
+fn test_bigint() {
+   // 2^80, in 21 groups, times itself.
+   let big = BigUint::from(1u8) << 80u8;
+   let literal = ElfPacket::literal(0, big.clone());
+   assert_eq!(literal.framing, Framing::Groups(21));
+   let product = ElfPacket::operator(0, ElfPType::Product,
+                                     vec![literal.clone(), literal]);
+   let packet = Day16::parse(&product.encode().unwrap()).unwrap();
+   assert_eq!(packet, product);
+   assert_eq!(Day16::part2(&packet), &big * &big);
+   assert_eq!(Day16::part2(&packet).to_string(),
+              "1461501637330902918203684832716283019655932542976");
+}
+
+#[test]
+fn test_optimize() {
+   use ElfPType::*;
+   let lit = |val: u32| ElfPacket::literal(1, val);
+   let op = |ptype, packets| ElfPacket::operator(2, ptype, packets);
+
+   // (+ 1 (+ 2 (* 3)) (max 4 (min 5))) is 11.
+   let packet = op(Sum, vec![
+      lit(1), op(Sum, vec![lit(2), op(Product, vec![lit(3)])]),
+      op(Max, vec![lit(4), op(Min, vec![lit(5)])]),
+   ]);
+   assert_eq!(packet.optimize(), ElfPacket::literal(2, 11u32));
+   assert_eq!(op(Less, vec![lit(3), lit(4)]).optimize(),
+              ElfPacket::literal(2, 1u32));
+   assert_eq!(op(Product, vec![]).optimize(), ElfPacket::literal(2, 1u32));
+   // Leading zero groups go.
+   assert_eq!(lit(7).with_framing(Framing::Groups(3)).optimize(), lit(7));
+
+   // Every example and generated transmission folds to its value, and
+   // encodes back.
+   let examples = fixture::cases(fixture!("part1.txt")).into_iter()
+      .chain(fixture::cases(fixture!("part2.txt")))
+      .map(|(hex, _)| hex.to_string());
+   let mut rng = aoc_common::Rng::new(16);
+   let generated = (0..100).map(|i| gen::case(&mut rng, 1 + i % 50).hex);
+   for hex in examples.chain(generated) {
+      let packet = Day16::parse(&hex).unwrap();
+      let optimized = packet.optimize();
+      assert_eq!(optimized,
+                 ElfPacket::literal(packet.header.version, packet.eval()));
+      assert!(optimized.bit_len() <= packet.bit_len());
+      let decoded = Day16::parse(&optimized.encode().unwrap()).unwrap();
+      assert_eq!(decoded, optimized);
+   }
+}
@@ -0,0 +1,286 @@
+// Decodes a hex transmission from any `io::Read` as a stream of packet
+// events, keeping open operators on an explicit stack rather than recursing.
+// Nothing is held but that stack, so transmissions of any size or depth are
+// fine.
+
+use std::io::{self, BufReader, Read};
+
+use super::*;
+
+// -
+
+#[derive(PartialEq,Eq,Clone,Debug)]
+pub enum Event {
+   // An operator, before its sub-packets.
+   Begin{at: u64, version: u8, ptype: ElfPType, framing: Framing},
+   // A literal. (No End follows, since it has no sub-packets.)
+   Literal{at: u64, version: u8, val: BigUint, groups: usize},
+   // The end of the innermost open operator, with the bit after it.
+   End{at: u64},
+}
+
+// Bits from hex digits, with optional trailing whitespace.
+struct HexBits<R: Read> {
+   bytes: io::Bytes<BufReader<R>>,
+   nibble: u8,
+   // Bits of `nibble` not read yet.
+   left: u8,
+   position: u64,
+}
+impl<R: Read> HexBits<R> {
+   fn next_nibble(&mut self) -> BitsResult<Option<u8>> {
+      let at = self.position;
+      let io = |e: io::Error| BitsError::Io{at, message: e.to_string()};
+      let byte = match self.bytes.next() {
+         None => return Ok(None),
+         Some(byte) => byte.map_err(io)?,
+      };
+      if byte.is_ascii_whitespace() {
+         // The end, as long as there's only more whitespace.
+         for byte in self.bytes.by_ref() {
+            let byte = byte.map_err(io)?;
+            if !byte.is_ascii_whitespace() {
+               return Err(BitsError::BadHex{at, found: byte as char});
+            }
+         }
+         return Ok(None);
+      }
+      match (byte as char).to_digit(16) {
+         Some(val) => Ok(Some(val as u8)),
+         None => Err(BitsError::BadHex{at, found: byte as char}),
+      }
+   }
+
+   // Ok(None) at the end.
+   fn bit(&mut self) -> BitsResult<Option<bool>> {
+      if self.left == 0 {
+         match self.next_nibble()? {
+            Some(nibble) => {
+               self.nibble = nibble;
+               self.left = 4;
+            },
+            None => return Ok(None),
+         }
+      }
+      self.left -= 1;
+      self.position += 1;
+      Ok(Some(self.nibble >> self.left & 1 == 1))
+   }
+
+   fn read(&mut self, bits: u8) -> BitsResult<u32> {
+      let at = self.position;
+      let mut val = 0;
+      for _ in 0..bits {
+         let bit = self.bit()?.ok_or(BitsError::Truncated{at, bits})?;
+         val = val << 1 | bit as u32;
+      }
+      Ok(val)
+   }
+}
+
+enum Until {
+   Bit(u64),
+   Count(u32),
+}
+
+struct Open {
+   at: u64,
+   ptype: ElfPType,
+   until: Until,
+   // Sub-packets finished so far.
+   count: usize,
+}
+
+pub struct Decoder<R: Read> {
+   bits: HexBits<R>,
+   stack: Vec<Open>,
+   started: bool,
+   finished: bool,
+}
+
+impl<R: Read> Decoder<R> {
+   pub fn new(r: R) -> Decoder<R> {
+      Decoder{
+         bits: HexBits{bytes: BufReader::new(r).bytes(), nibble: 0, left: 0,
+                       position: 0},
+         stack: Vec::new(),
+         started: false,
+         finished: false,
+      }
+   }
+
+   // A sub-packet of the innermost open operator just finished.
+   fn finished_one(&mut self) {
+      if let Some(open) = self.stack.last_mut() {
+         open.count += 1;
+      }
+   }
+
+   fn next_event(&mut self) -> BitsResult<Option<Event>> {
+      let at = self.bits.position;
+      if let Some(open) = self.stack.last() {
+         let done = match open.until {
+            Until::Bit(end) if at > end => {
+               return Err(BitsError::Overrun{at: open.at, end, reached: at});
+            },
+            Until::Bit(end) => at == end,
+            Until::Count(n) => open.count == n as usize,
+         };
+         if done {
+            open.ptype.check_count(open.at, open.count)?;
+            self.stack.pop();
+            self.finished_one();
+            return Ok(Some(Event::End{at: self.bits.position}));
+         }
+      } else if self.started {
+         // The outermost packet is done: the rest must be padding.
+         loop {
+            let at = self.bits.position;
+            match self.bits.bit()? {
+               None => return Ok(None),
+               Some(true) => return Err(BitsError::TrailingData{at}),
+               Some(false) => {},
+            }
+         }
+      }
+
+      self.started = true;
+      let version = self.bits.read(3)? as u8;
+      let ptype = ElfHeader::ptype(self.bits.read(3)?);
+      if ptype == ElfPType::Literal {
+         let mut val = BigUint::default();
+         let mut groups = 0;
+         loop {
+            let group = self.bits.read(5)?;
+            groups += 1;
+            val = val << 4u8 | BigUint::from(group & 0b01111);
+            if group & 0b10000 == 0 { break; }
+         }
+         self.finished_one();
+         return Ok(Some(Event::Literal{at, version, val, groups}));
+      }
+      let (framing, until) = if self.bits.read(1)? == 0 {
+         let num_bits = self.bits.read(15)? as u64;
+         (Framing::BitCount, Until::Bit(self.bits.position + num_bits))
+      } else {
+         (Framing::PacketCount, Until::Count(self.bits.read(11)?))
+      };
+      self.stack.push(Open{at, ptype, until, count: 0});
+      Ok(Some(Event::Begin{at, version, ptype, framing}))
+   }
+}
+
+impl<R: Read> Iterator for Decoder<R> {
+   type Item = BitsResult<Event>;
+
+   // Stops after the first error.
+   fn next(&mut self) -> Option<Self::Item> {
+      if self.finished {
+         return None;
+      }
+      let ret = self.next_event().transpose();
+      if !matches!(ret, Some(Ok(_))) {
+         self.finished = true;
+      }
+      ret
+   }
+}
+
+// -
+
+pub fn version_sum<R: Read>(r: R) -> BitsResult<usize> {
+   let mut sum = 0;
+   for event in Decoder::new(r) {
+      match event? {
+         Event::Begin{version, ..} | Event::Literal{version, ..} => {
+            sum += version as usize;
+         },
+         Event::End{..} => {},
+      }
+   }
+   Ok(sum)
+}
+
+// Folds each operand into its operator as soon as it's known.
+pub fn eval<R: Read>(r: R) -> BitsResult<BigUint> {
+   // (operator, value so far)
+   let mut stack: Vec<(ElfPType, Option<BigUint>)> = Vec::new();
+   let mut result = None;
+   for event in Decoder::new(r) {
+      let val = match event? {
+         Event::Begin{ptype, ..} => {
+            stack.push((ptype, None));
+            continue;
+         },
+         Event::Literal{val, ..} => val,
+         Event::End{..} => {
+            let (ptype, acc) = stack.pop().unwrap();
+            acc.or_else(|| ptype.op().identity()).unwrap()
+         },
+      };
+      let (ptype, acc) = match stack.last_mut() {
+         Some(top) => top,
+         None => {
+            result = Some(val);
+            continue;
+         },
+      };
+      *acc = Some(match acc.take() {
+         None => val,
+         Some(a) => (ptype.op().fold)(a, val),
+      });
+   }
+   Ok(result.unwrap_or_default())
+}
+
+// -
+
+#[test]
+fn test_stream() {
+   let hex = "38006F45291200";
+   let events: Vec<Event> = Decoder::new(hex.as_bytes())
+      .collect::<BitsResult<_>>().unwrap();
+   assert_eq!(events, [
+      Event::Begin{at: 0, version: 1, ptype: ElfPType::Less,
+                   framing: Framing::BitCount},
+      Event::Literal{at: 22, version: 6, val: 10u32.into(), groups: 1},
+      Event::Literal{at: 33, version: 2, val: 20u32.into(), groups: 2},
+      Event::End{at: 49},
+   ]);
+
+   // Agrees with the tree.
+   let examples = fixture::cases(fixture!("part1.txt")).into_iter()
+      .chain(fixture::cases(fixture!("part2.txt")))
+      .map(|(hex, _)| hex.to_string());
+   let mut rng = aoc_common::Rng::new(15);
+   let generated = (0..200).map(|i| gen::case(&mut rng, 1 + i % 50).hex);
+   for hex in examples.chain(generated) {
+      let tree = Day16::parse(&hex).unwrap();
+      assert_eq!(version_sum(hex.as_bytes()).unwrap(), Day16::part1(&tree));
+      assert_eq!(eval(format!("{}\n", hex).as_bytes()).unwrap(),
+                 Day16::part2(&tree));
+   }
+
+   // Far deeper than the tree decoder allows.
+   let depth = 100_000;
+   let mut w = BitWriter::new();
+   for _ in 0..depth {
+      w.write(1, 3);
+      w.write(ElfPType::Product as usize, 3);
+      w.write(1, 1);
+      w.write(1, 11);
+   }
+   w.write(1, 3);
+   w.write(ElfPType::Literal as usize, 3);
+   w.write(7, 5);
+   let hex = w.to_hex();
+   assert_eq!(version_sum(hex.as_bytes()).unwrap(), depth + 1);
+   assert_eq!(eval(hex.as_bytes()).unwrap(), 7u32.into());
+
+   let err = |hex: &str| eval(hex.as_bytes()).unwrap_err();
+   assert_eq!(err("D2FE"), BitsError::Truncated{at: 16, bits: 5});
+   assert_eq!(err("D2FE29"), BitsError::TrailingData{at: 23});
+   assert_eq!(err("D2FE2X"), BitsError::BadHex{at: 20, found: 'X'});
+   assert_eq!(err("D2FE28\nD2"), BitsError::BadHex{at: 24, found: 'D'});
+   assert_eq!(eval("C200B40A82\n".as_bytes()).unwrap(), 3u32.into());
+}
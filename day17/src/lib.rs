@@ -0,0 +1,195 @@
+
+use std::cmp::max;
+use log::{debug, info};
+use aoc_common::{Result, Solution, Source, Vector};
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+   type Input = Area;
+   type Answer1 = usize;
+   type Answer2 = usize;
+   const DAY: u32 = 17;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse_inputs(input)
+   }
+   fn part1(area: &Self::Input) -> usize {
+      part1(area)
+   }
+   fn part2(area: &Self::Input) -> usize {
+      part2(area)
+   }
+}
+
+// -
+
+// (x, y)
+type Vec2 = Vector<2>;
+
+struct Range {
+   a: i64,
+   b: i64,
+}
+impl Range {
+   fn parse(src: &Source, s: &str) -> Result<Range> {
+      let (a,b) = src.split_once(s, "..")?;
+      let ret = Range{a: src.parse(a, "an integer")?,
+       b: src.parse(b, "an integer")?};
+      if ret.a > ret.b {
+         return Err(src.error(b, &format!("at least {}", ret.a)));
+      }
+      Ok(ret)
+   }
+   fn contains(&self, c: i64) -> bool {
+      self.a <= c && c <= self.b
+   }
+   fn cmp(&self, c: i64) -> isize {
+      if c < self.a { -1}
+      else if c > self.b { 1}
+      else { 0}
+   }
+}
+
+pub struct Area {
+   x: Range,
+   y: Range,
+}
+impl Area {
+   fn contains(&self, p: &Vec2) -> bool {
+      self.x.contains(p[0]) && self.y.contains(p[1])
+   }
+}
+
+fn parse_inputs(input: &str) -> Result<Area> {
+   let src = Source::new(input);
+   let input2 = src.strip_prefix(input.trim(), "target area: x=")?;
+   let (sx,sy) = src.split_once(input2, ", y=")?;
+   Ok(Area{x: Range::parse(&src, sx)?,
+           y: Range::parse(&src, sy)?})
+}
+
+fn step(p: &mut Vec2, v: &mut Vec2) {
+   *p += *v;
+   // Drag:
+   v[0] -= v[0].signum();
+   v[1] -= 1; // Gravity;
+}
+
+// "What is the highest y position it reaches on this trajectory?"
+fn part1(area: &Area) -> usize {
+   let x0 = area.x.a; // Irrelevant to y.
+   let p0 = Vec2::new([x0, 0]);
+   let mut best = (p0, 0);
+   for yv0 in -100..10000 {
+      if yv0 % 100 == 0 {
+         info!("trying yv0 {}...", yv0);
+      }
+      let mut p = p0;
+      let mut v = Vec2::new([0, yv0]);
+      let mut max_y = p[1];
+      while area.y.cmp(p[1]) == -1 {
+         step(&mut p, &mut v);
+         max_y = max(max_y, p[1]);
+      }
+      while area.y.cmp(p[1]) == 1 {
+         step(&mut p, &mut v);
+         max_y = max(max_y, p[1]);
+      }
+      if area.y.cmp(p[1]) == 0 && max_y > best.1 {
+         best = (v, max_y);
+         debug!("new best: {:?}", best);
+      }
+   }
+   best.1 as usize
+}
+
+// "How many distinct initial velocity values cause the probe to be within
+//  the target area after any step?"
+fn part2(area: &Area) -> usize {
+   let possible_yv0s = {
+      let p0 = Vec2::zero();
+      let mut ret = Vec::new();
+      for yv0 in -200..1000 {
+         if yv0 % 100 == 0 {
+            info!("trying yv0 {}...", yv0);
+         }
+         let mut p = p0;
+         let mut v = Vec2::new([0, yv0]);
+         while area.y.cmp(p[1]) == -1 {
+            step(&mut p, &mut v);
+         }
+         while area.y.cmp(p[1]) == 1 {
+            step(&mut p, &mut v);
+         }
+         if area.y.cmp(p[1]) == 0 {
+            ret.push(yv0);
+            debug!("y hit at {:?}", yv0);
+         }
+      }
+      ret
+   };
+   let possible_xv0s = {
+      let p0 = Vec2::zero();
+      let mut ret = Vec::new();
+      for xv0 in 0..1000 {
+         if xv0 % 100 == 0 {
+            info!("trying xv0 {}...", xv0);
+         }
+         let mut p = p0;
+         let mut v = Vec2::new([xv0, 0]);
+         while v[0] > 0 && area.x.cmp(p[0]) == -1 {
+            step(&mut p, &mut v);
+         }
+         if area.x.cmp(p[0]) == 0 {
+            ret.push(xv0);
+            debug!("x hit at {:?}", xv0);
+         }
+      }
+      ret
+   };
+
+   let possible_v0s = {
+      let p0 = Vec2::zero();
+
+      let mut ret = Vec::new();
+      for yv0 in possible_yv0s.iter() {
+         for xv0 in possible_xv0s.iter() {
+            let v0 = Vec2::new([*xv0, *yv0]);
+            let mut p = p0;
+            let mut v = v0;
+            while area.y.cmp(p[1]) == -1 {
+               step(&mut p, &mut v);
+            }
+            while area.y.cmp(p[1]) == 1 {
+               step(&mut p, &mut v);
+            }
+            while area.y.cmp(p[1]) != -1 {
+               if area.contains(&p) {
+                  debug!("v0 hit #{} at {:?}", ret.len(), v0);
+                  ret.push(v0);
+                  break;
+               }
+               step(&mut p, &mut v);
+            }
+         }
+      }
+      ret
+   };
+   possible_v0s.len()
+}
+
+// -
+
+#[test]
+fn test_example() {
+   let input = fixture!("example.txt");
+   assert_eq!(Day17::solve1(input), 45);
+   assert_eq!(Day17::solve2(input), 112);
+
+   let e = Day17::load("target area: x=20..30, y=-10").err().unwrap();
+   assert_eq!(e.to_string(),
+              "day17: line 1, column 26: expected \"..\", found \"-10\"");
+}
@@ -0,0 +1,152 @@
+// Random homework: lines of already-reduced snailfish numbers.
+
+use aoc_common::Rng;
+
+// -
+
+// A reduced number: nested at most four deep, with regulars 0-9.
+fn number(rng: &mut Rng, depth: usize, out: &mut String) {
+   // The top level is always a pair; deeper, pairs get rarer.
+   let pair = depth == 0 || (depth < 4 && rng.below(4) >= depth as u64);
+   if pair {
+      out.push('[');
+      number(rng, depth + 1, out);
+      out.push(',');
+      number(rng, depth + 1, out);
+      out.push(']');
+   } else {
+      out.push_str(&rng.below(10).to_string());
+   }
+}
+
+// `size` snailfish numbers, one per line.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+   let mut ret = String::new();
+   for _ in 0..size.max(1) {
+      number(rng, 0, &mut ret);
+      ret.push('\n');
+   }
+   ret
+}
+
+// -
+
+// The reference: reduce a flat token list, as the puzzle describes it.
+#[cfg(test)]
+mod reference {
+   #[derive(Clone,Copy,PartialEq)]
+   enum Tok {
+      Open,
+      Close,
+      Num(i64),
+   }
+   use Tok::*;
+
+   fn tokens(s: &str) -> Vec<Tok> {
+      s.chars().filter_map(|c| match c {
+         '[' => Some(Open),
+         ']' => Some(Close),
+         ',' => None,
+         _ => Some(Num(c.to_digit(10).unwrap() as i64)),
+      }).collect()
+   }
+
+   fn explode(toks: &mut Vec<Tok>) -> bool {
+      let mut depth = 0;
+      for i in 0..toks.len() {
+         match toks[i] {
+            Open => depth += 1,
+            Close => depth -= 1,
+            Num(_) => continue,
+         }
+         if depth != 5 {
+            continue;
+         }
+         let (l, r) = match toks[i+1..i+4] {
+            [Num(l), Num(r), Close] => (l, r),
+            _ => continue,
+         };
+         if let Some(Num(v)) = toks[..i].iter_mut().rev()
+               .find(|t| matches!(t, Num(_))) {
+            *v += l;
+         }
+         if let Some(Num(v)) = toks[i+4..].iter_mut()
+               .find(|t| matches!(t, Num(_))) {
+            *v += r;
+         }
+         toks.splice(i..i+4, [Num(0)]);
+         return true;
+      }
+      false
+   }
+
+   fn split(toks: &mut Vec<Tok>) -> bool {
+      for i in 0..toks.len() {
+         if let Num(v) = toks[i] {
+            if v >= 10 {
+               toks.splice(i..i+1, [Open, Num(v / 2), Num(v - v / 2), Close]);
+               return true;
+            }
+         }
+      }
+      false
+   }
+
+   fn magnitude(toks: &[Tok], i: &mut usize) -> i64 {
+      let tok = toks[*i];
+      *i += 1;
+      match tok {
+         Num(v) => v,
+         Open => {
+            let l = magnitude(toks, i);
+            let r = magnitude(toks, i);
+            *i += 1; // Close
+            3*l + 2*r
+         },
+         Close => panic!(),
+      }
+   }
+
+   fn add(a: &[Tok], b: &[Tok]) -> Vec<Tok> {
+      let mut sum = vec![Open];
+      sum.extend_from_slice(a);
+      sum.extend_from_slice(b);
+      sum.push(Close);
+      while explode(&mut sum) || split(&mut sum) {}
+      sum
+   }
+
+   pub fn part1(lines: &[&str]) -> i64 {
+      let sum = lines.iter().map(|s| tokens(s))
+         .reduce(|a, b| add(&a, &b)).unwrap();
+      magnitude(&sum, &mut 0)
+   }
+
+   pub fn part2(lines: &[&str]) -> i64 {
+      let nums: Vec<_> = lines.iter().map(|s| tokens(s)).collect();
+      let mut best = 0;
+      for (i, a) in nums.iter().enumerate() {
+         for (j, b) in nums.iter().enumerate() {
+            if i != j {
+               best = best.max(magnitude(&add(a, b), &mut 0));
+            }
+         }
+      }
+      best
+   }
+}
+
+#[test]
+fn test_gen() {
+   use aoc_common::Solution;
+   use crate::Day18;
+
+   for seed in 0..100 {
+      let mut rng = Rng::new(seed);
+      let input = input(&mut rng, 2 + seed as usize % 8);
+      let lines: Vec<&str> = input.lines().collect();
+      let parsed = Day18::parse(&input).unwrap();
+      assert_eq!(Day18::part1(&parsed), reference::part1(&lines), "{}", input);
+      assert_eq!(Day18::part2(&parsed), reference::part2(&lines), "{}", input);
+   }
+}
@@ -0,0 +1,131 @@
+use aoc_common::{Result, Solution, Source};
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub mod gen;
+pub mod number;
+
+use number::SnailNumber;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+   // One snailfish number per line.
+   type Input = Vec<SnailNumber>;
+   type Answer1 = i64;
+   type Answer2 = i64;
+   const DAY: u32 = 18;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse_inputs(input)
+   }
+   // "Add up all of the snailfish numbers from the homework assignment in
+   //  the order they appear. What is the magnitude of the final sum?"
+   fn part1(nums: &Self::Input) -> i64 {
+      solve_p1(nums).0
+   }
+   // "What is the largest magnitude of any sum of two different snailfish
+   //  numbers from the homework assignment?"
+   fn part2(nums: &Self::Input) -> i64 {
+      solve_p2(nums).0
+   }
+}
+
+// -
+
+fn parse_inputs(input: &str) -> Result<Vec<SnailNumber>> {
+   let src = Source::new(input);
+   let nums: Vec<_> = input.trim().lines()
+      .map(|s| SnailNumber::parse(&src, s)).collect::<Result<_>>()?;
+   if nums.is_empty() {
+      return Err(src.error(input.trim(), "a snailfish number"));
+   }
+   Ok(nums)
+}
+
+// -
+
+#[cfg(test)]
+fn assert_adds_to(input: &str, expected: &str) {
+   // Every step, to show where it went wrong.
+   let nums = Day18::parse(input).unwrap();
+   let mut traces = String::new();
+   let sum = nums[1..].iter().fold(nums[0].clone(), |acc, next| {
+      let trace = acc.add_traced(next);
+      traces += &trace.to_string();
+      trace.result().clone()
+   });
+   assert_eq!(sum, solve_p1(&nums).1.parse().unwrap());
+   assert_eq!(sum.to_string(), expected, "\n{}", traces);
+}
+
+fn solve_p1(nums: &[SnailNumber]) -> (i64, String) {
+   let sum: SnailNumber = nums.iter().sum();
+   (sum.magnitude(), sum.to_string())
+}
+
+fn solve_p2(nums: &[SnailNumber]) -> (i64, String) {
+   let best = nums.iter().enumerate().flat_map(|(ai, a)| {
+      nums.iter().enumerate().filter(move |&(bi, _)| bi != ai)
+         .map(move |(_, b)| a + b)
+   }).max().unwrap();
+   (best.magnitude(), best.to_string())
+}
+
+// -
+
+#[test]
+fn test_example() {
+   assert_adds_to("\
+[1,1]
+[2,2]
+[3,3]
+[4,4]
+", "[[[[1,1],[2,2]],[3,3]],[4,4]]");
+
+   assert_adds_to("\
+[1,1]
+[2,2]
+[3,3]
+[4,4]
+[5,5]
+", "[[[[3,0],[5,3]],[4,4]],[5,5]]");
+
+   assert_adds_to("\
+[1,1]
+[2,2]
+[3,3]
+[4,4]
+[5,5]
+[6,6]
+", "[[[[5,0],[7,4]],[5,5]],[6,6]]");
+   assert_adds_to("\
+[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]
+[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]
+[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]
+[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]
+[7,[5,[[3,8],[1,4]]]]
+[[2,[2,2]],[8,[8,1]]]
+[2,9]
+[1,[[[9,3],9],[[9,0],[0,7]]]]
+[[[5,[7,4]],7],1]
+[[[[4,2],2],6],[8,7]]
+", "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]");
+
+   let mag = |s| Day18::parse(s).unwrap()[0].magnitude();
+   assert_eq!(mag("[[1,2],[[3,4],5]]"), 143);
+   assert_eq!(mag("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"), 1384);
+   assert_eq!(mag("[[[[1,1],[2,2]],[3,3]],[4,4]]"), 445);
+   assert_eq!(mag("[[[[3,0],[5,3]],[4,4]],[5,5]]"), 791);
+   assert_eq!(mag("[[[[5,0],[7,4]],[5,5]],[6,6]]"), 1137);
+   assert_eq!(mag("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"), 3488);
+   let example = fixture!("example.txt");
+   {
+      let (mag, res) = solve_p1(&Day18::parse(example).unwrap());
+      assert_eq!(res, "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]");
+      assert_eq!(mag, 4140);
+      let (mag, res) = solve_p2(&Day18::parse(example).unwrap());
+      assert_eq!(res, "[[[[7,8],[6,6]],[[6,0],[7,7]]],[[[7,8],[8,8]],[[7,9],[0,6]]]]");
+      assert_eq!(mag, 3993);
+   }
+}
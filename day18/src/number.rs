@@ -0,0 +1,434 @@
+// Snailfish numbers, as values: parse them, print them, add them up.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::Add;
+use std::str::FromStr;
+
+use aoc_common::{ParseError, Result, Source};
+
+// -
+
+// A regular number, and how many pairs it's inside.
+#[derive(PartialEq,Eq,Clone,Copy,Debug)]
+struct Leaf {
+   val: i64,
+   depth: usize,
+}
+
+// Its regular numbers, left to right. The depths are enough to tell where
+// the pairs are: two neighbours at the same depth, with nothing deeper
+// between them, are a pair.
+#[derive(PartialEq,Eq,Clone,Debug)]
+pub struct SnailNumber {
+   leaves: Vec<Leaf>,
+}
+
+#[derive(PartialEq,Eq,Clone,Copy,Debug)]
+enum Token {
+   Open,
+   Close,
+   Comma,
+   // Any non-negative integer, reduced or not.
+   Regular(i64),
+   // Anything else, left for the parser to complain about.
+   Other,
+}
+
+// Tokens, each with the rest of the line from where it starts (for errors).
+// Whitespace between them is skipped.
+struct Tokens<'a> {
+   s: &'a str,
+}
+impl<'a> Tokens<'a> {
+   fn next(&mut self) -> (Token, &'a str) {
+      let at = self.s.trim_start();
+      let len = at.find(|c: char| !c.is_ascii_digit()).unwrap_or(at.len());
+      let (tok, len) = match at.chars().next() {
+         Some('[') => (Token::Open, 1),
+         Some(']') => (Token::Close, 1),
+         Some(',') => (Token::Comma, 1),
+         Some(_) if len > 0 => match at[..len].parse() {
+            Ok(val) => (Token::Regular(val), len),
+            Err(_) => (Token::Other, 0),
+         },
+         _ => (Token::Other, 0),
+      };
+      self.s = &at[len..];
+      (tok, at)
+   }
+}
+
+// One thing reduction did, and what it left.
+#[derive(PartialEq,Eq,Clone,Debug)]
+pub struct ReductionStep {
+   pub action: Action,
+   pub result: SnailNumber,
+}
+
+#[derive(PartialEq,Eq,Clone,Copy,Debug)]
+pub enum Action {
+   // This pair exploded.
+   Explode(i64, i64),
+   // This regular number split.
+   Split(i64),
+}
+
+// An addition, step by step. Displays like the puzzle's worked example:
+//    after addition: [[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]
+//    after explode:  [[[[0,7],4],[7,[[8,4],9]]],[1,1]]
+#[derive(PartialEq,Eq,Clone,Debug)]
+pub struct Trace {
+   // Before reducing.
+   pub sum: SnailNumber,
+   pub steps: Vec<ReductionStep>,
+}
+impl Trace {
+   pub fn result(&self) -> &SnailNumber {
+      self.steps.last().map(|step| &step.result).unwrap_or(&self.sum)
+   }
+}
+impl fmt::Display for Trace {
+   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      writeln!(f, "after addition: {}", self.sum)?;
+      for step in self.steps.iter() {
+         let action = match step.action {
+            Action::Explode(..) => "explode:",
+            Action::Split(_) => "split:",
+         };
+         writeln!(f, "after {:9} {}", action, step.result)?;
+      }
+      Ok(())
+   }
+}
+
+impl SnailNumber {
+   // One line of `src`: "[[1,2],3]", with regulars of any size, and
+   // whitespace anywhere between tokens. No recursion, so any depth is fine.
+   pub fn parse(src: &Source, s: &str) -> Result<SnailNumber> {
+      let mut toks = Tokens{s};
+      let mut leaves = Vec::new();
+      // For each pair we're inside: whether its comma's been read.
+      let mut open: Vec<bool> = Vec::new();
+      match toks.next() {
+         (Token::Open, _) => open.push(false),
+         (_, at) => return Err(src.error(at, "\"[\"")),
+      }
+      while !open.is_empty() {
+         // A pair or regular number...
+         match toks.next() {
+            (Token::Open, _) => {
+               open.push(false);
+               continue;
+            },
+            (Token::Regular(val), _) => {
+               leaves.push(Leaf{val, depth: open.len()});
+            },
+            (_, at) => {
+               return Err(src.error(at, "\"[\" or a regular number"));
+            },
+         }
+         // ...and then whatever follows it, closing any finished pairs.
+         while let Some(&comma) = open.last() {
+            let (tok, at) = toks.next();
+            match (comma, tok) {
+               (false, Token::Comma) => {
+                  *open.last_mut().unwrap() = true;
+                  break;
+               },
+               (true, Token::Close) => {
+                  open.pop();
+               },
+               (false, _) => return Err(src.error(at, "\",\"")),
+               (true, _) => return Err(src.error(at, "\"]\"")),
+            }
+         }
+      }
+      let extra = toks.s.trim_start();
+      if !extra.is_empty() {
+         return Err(src.error(extra, "end of line"));
+      }
+      Ok(SnailNumber{leaves})
+   }
+
+   // Adds, like `+`, keeping every step.
+   pub fn add_traced(&self, other: &SnailNumber) -> Trace {
+      let sum = self.concat(other);
+      let mut steps = Vec::new();
+      sum.clone().reduce_traced(Some(&mut steps));
+      Trace{sum, steps}
+   }
+
+   // The pair of the two, unreduced.
+   fn concat(&self, other: &SnailNumber) -> SnailNumber {
+      let leaves = self.leaves.iter().chain(other.leaves.iter())
+         .map(|leaf| Leaf{val: leaf.val, depth: leaf.depth + 1}).collect();
+      SnailNumber{leaves}
+   }
+
+   // Pairs fold up as soon as both halves are known.
+   pub fn magnitude(&self) -> i64 {
+      let mut stack: Vec<Leaf> = Vec::new();
+      for &leaf in self.leaves.iter() {
+         let mut leaf = leaf;
+         while let Some(&left) = stack.last() {
+            if left.depth != leaf.depth {
+               break;
+            }
+            stack.pop();
+            leaf = Leaf{val: 3*left.val + 2*leaf.val, depth: leaf.depth - 1};
+         }
+         stack.push(leaf);
+      }
+      stack[0].val
+   }
+
+   // To explode a pair, the pair's left value is added to the first
+   // regular number to the left of the exploding pair (if any), and the
+   // pair's right value is added to the first regular number to the right
+   // of the exploding pair (if any). Exploding pairs will always consist
+   // of two regular numbers. Then, the entire exploding pair is replaced
+   // with the regular number 0.
+   // (The pair's already down to one leaf, at `i`.)
+   fn explode(&mut self, i: usize, left: i64, right: i64, depth: usize) {
+      let leaves = &mut self.leaves;
+      if i > 0 {
+         leaves[i - 1].val += left;
+      }
+      if let Some(next) = leaves.get_mut(i + 1) {
+         next.val += right;
+      }
+      leaves[i] = Leaf{val: 0, depth: depth - 1};
+   }
+
+   pub fn reduce(&mut self) {
+      self.reduce_traced(None);
+   }
+
+   // Steps are only recorded (and the number copied for each) if there's
+   // somewhere to put them.
+   fn reduce_traced(&mut self, mut trace: Option<&mut Vec<ReductionStep>>) {
+      let mut record = |n: &SnailNumber, action| {
+         if let Some(trace) = trace.as_deref_mut() {
+            trace.push(ReductionStep{action, result: n.clone()});
+         }
+      };
+
+      // 1. If any pair is nested inside four pairs, the leftmost such
+      //    pair explodes.
+      // Exploding only changes its neighbours, so one pass does them all.
+      // (Going back a step, in case the 0 left behind makes a new pair.)
+      let mut i = 0;
+      while i + 1 < self.leaves.len() {
+         let (a, b) = (self.leaves[i], self.leaves[i + 1]);
+         if a.depth > 4 && a.depth == b.depth {
+            self.leaves.remove(i + 1);
+            self.explode(i, a.val, b.val, a.depth);
+            record(self, Action::Explode(a.val, b.val));
+            i = i.saturating_sub(1);
+         } else {
+            i += 1;
+         }
+      }
+
+      // 2. If any regular number is 10 or greater, the leftmost such
+      //    regular number splits.
+      // Nothing is nested too deeply now, so a split pair is the only thing
+      // that can explode, and does so at once. That can only push the
+      // number to its left past 9, so the search carries on from there.
+      let mut i = 0;
+      while i < self.leaves.len() {
+         let Leaf{val, depth} = self.leaves[i];
+         if val < 10 {
+            i += 1;
+            continue;
+         }
+         let (lval, rval) = (val / 2, val - val / 2);
+         let pair = [Leaf{val: lval, depth: depth + 1},
+                     Leaf{val: rval, depth: depth + 1}];
+         if depth + 1 > 4 {
+            // (Only to show it: the pair never really goes in.)
+            let mut split = self.clone();
+            split.leaves.splice(i..=i, pair);
+            record(&split, Action::Split(val));
+            self.explode(i, lval, rval, depth + 1);
+            record(self, Action::Explode(lval, rval));
+            i = i.saturating_sub(1);
+         } else {
+            self.leaves.splice(i..=i, pair);
+            record(self, Action::Split(val));
+         }
+      }
+   }
+}
+
+impl FromStr for SnailNumber {
+   type Err = ParseError;
+
+   fn from_str(s: &str) -> Result<SnailNumber> {
+      SnailNumber::parse(&Source::new(s), s.trim())
+   }
+}
+
+fn write_node(f: &mut fmt::Formatter, leaves: &[Leaf], i: &mut usize,
+              depth: usize) -> fmt::Result {
+   let leaf = leaves[*i];
+   if leaf.depth == depth {
+      *i += 1;
+      return write!(f, "{}", leaf.val);
+   }
+   write!(f, "[")?;
+   write_node(f, leaves, i, depth + 1)?;
+   write!(f, ",")?;
+   write_node(f, leaves, i, depth + 1)?;
+   write!(f, "]")
+}
+
+impl fmt::Display for SnailNumber {
+   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      write_node(f, &self.leaves, &mut 0, 0)
+   }
+}
+
+// Adding always reduces.
+impl Add<&SnailNumber> for &SnailNumber {
+   type Output = SnailNumber;
+
+   fn add(self, other: &SnailNumber) -> SnailNumber {
+      let mut sum = self.concat(other);
+      sum.reduce();
+      sum
+   }
+}
+impl Add for SnailNumber {
+   type Output = SnailNumber;
+
+   fn add(self, other: SnailNumber) -> SnailNumber {
+      &self + &other
+   }
+}
+
+// There's no zero, so summing nothing panics.
+impl Sum for SnailNumber {
+   fn sum<I: Iterator<Item = SnailNumber>>(iter: I) -> SnailNumber {
+      iter.reduce(|acc, next| acc + next).expect("no snailfish numbers")
+   }
+}
+impl<'a> Sum<&'a SnailNumber> for SnailNumber {
+   fn sum<I: Iterator<Item = &'a SnailNumber>>(mut iter: I) -> SnailNumber {
+      let first = iter.next().expect("no snailfish numbers").clone();
+      iter.fold(first, |acc, next| &acc + next)
+   }
+}
+
+// By magnitude, and then (so that only equal numbers are equal) leaf by
+// leaf.
+impl Ord for SnailNumber {
+   fn cmp(&self, other: &SnailNumber) -> Ordering {
+      let leaves = |n: &SnailNumber| {
+         n.leaves.iter().map(|l| (l.val, l.depth)).collect::<Vec<_>>()
+      };
+      self.magnitude().cmp(&other.magnitude())
+         .then_with(|| leaves(self).cmp(&leaves(other)))
+   }
+}
+impl PartialOrd for SnailNumber {
+   fn partial_cmp(&self, other: &SnailNumber) -> Option<Ordering> {
+      Some(self.cmp(other))
+   }
+}
+
+// -
+
+#[test]
+fn test_number() {
+   let num = |s: &str| s.parse::<SnailNumber>().unwrap();
+
+   for s in ["[1,2]", "[[1,2],3]", "[9,[8,7]]", "[[1,9],[8,5]]",
+             "[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]"] {
+      assert_eq!(num(s).to_string(), s);
+   }
+   assert_eq!(num("[[1,2],[[3,4],5]]").magnitude(), 143);
+   assert_eq!(num("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").magnitude(), 1384);
+   assert_eq!(num("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]")
+                 .magnitude(), 3488);
+
+   // "after addition: [[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]"
+   assert_eq!(num("[[[[4,3],4],4],[7,[[8,4],9]]]") + num("[1,1]"),
+              num("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"));
+   let sum: SnailNumber = ["[1,1]", "[2,2]", "[3,3]", "[4,4]", "[5,5]"]
+      .iter().map(|s| num(s)).sum();
+   assert_eq!(sum.to_string(), "[[[[3,0],[5,3]],[4,4]],[5,5]]");
+
+   assert!(num("[9,1]") > num("[1,9]"));
+   assert!(num("[[1,2],3]") > num("[3,[2,1]]"));
+   assert_ne!(num("[1,5]").cmp(&num("[3,2]")), Ordering::Equal);
+
+   let err = "[1,2".parse::<SnailNumber>().unwrap_err();
+   assert_eq!((err.line, err.col, err.expected.as_str()), (1, 5, "\"]\""));
+   assert!("[1;2]".parse::<SnailNumber>().is_err());
+   assert!("[1,2]]".parse::<SnailNumber>().is_err());
+}
+
+#[test]
+fn test_parse() {
+   let num = |s: &str| s.parse::<SnailNumber>().unwrap();
+   assert_eq!(num("[[15,3],2]").to_string(), "[[15,3],2]");
+   assert_eq!(num(" [ [1 , 2] ,\t3 ] ").to_string(), "[[1,2],3]");
+   let deep = "[".repeat(10000) + "1" + &",2]".repeat(10000);
+   assert_eq!(num(&deep).to_string().len(), deep.len());
+
+   let err = |s: &str| {
+      let err = s.parse::<SnailNumber>().unwrap_err();
+      (err.col, err.expected)
+   };
+   assert_eq!(err("1"), (1, "\"[\"".to_string()));
+   assert_eq!(err("[1 2]"), (4, "\",\"".to_string()));
+   assert_eq!(err("[1,]"), (4, "\"[\" or a regular number".to_string()));
+   assert_eq!(err("[1,-2]"), (4, "\"[\" or a regular number".to_string()));
+   assert_eq!(err("[[1,2],3"), (9, "\"]\"".to_string()));
+   assert_eq!(err("[[1,2],3]]"), (10, "end of line".to_string()));
+   assert_eq!(err("[[1,2]3]"), (7, "\",\"".to_string()));
+   assert_eq!(err("[99999999999999999999,1]").0, 2);
+
+   // Reducing from any step of the puzzle's example gets to the same place.
+   let steps = [
+      "[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]",
+      "[[[[0,7],4],[7,[[8,4],9]]],[1,1]]",
+      "[[[[0,7],4],[15,[0,13]]],[1,1]]",
+      "[[[[0,7],4],[[7,8],[0,13]]],[1,1]]",
+      "[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]",
+      "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]",
+   ];
+   for step in steps {
+      let mut n = num(step);
+      n.reduce();
+      assert_eq!(n.to_string(), steps[5]);
+   }
+}
+
+#[test]
+fn test_trace() {
+   let num = |s: &str| s.parse::<SnailNumber>().unwrap();
+   let trace = num("[[[[4,3],4],4],[7,[[8,4],9]]]").add_traced(&num("[1,1]"));
+   assert_eq!(trace.to_string(), "\
+after addition: [[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]
+after explode:  [[[[0,7],4],[7,[[8,4],9]]],[1,1]]
+after explode:  [[[[0,7],4],[15,[0,13]]],[1,1]]
+after split:    [[[[0,7],4],[[7,8],[0,13]]],[1,1]]
+after split:    [[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]
+after explode:  [[[[0,7],4],[[7,8],[6,0]]],[8,1]]
+");
+   let actions: Vec<Action> = trace.steps.iter().map(|s| s.action).collect();
+   assert_eq!(actions, [Action::Explode(4, 3), Action::Explode(8, 4),
+                        Action::Split(15), Action::Split(13),
+                        Action::Explode(6, 7)]);
+   assert_eq!(*trace.result(),
+              num("[[[[4,3],4],4],[7,[[8,4],9]]]") + num("[1,1]"));
+
+   // Nothing to do.
+   let trace = num("[1,2]").add_traced(&num("[3,4]"));
+   assert!(trace.steps.is_empty());
+   assert_eq!(trace.result().to_string(), "[[1,2],[3,4]]");
+}
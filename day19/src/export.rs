@@ -0,0 +1,177 @@
+// The assembled map, for looking at in point-cloud tools: every scanner's
+// pose, and every beacon with the scanners that saw it. Or the overlaps
+// between scanners, for Graphviz.
+
+use std::fmt::Write;
+
+use super::*;
+
+// -
+
+// Position, and rotation rows, relative to the first scanner.
+fn pose(s: &Scanner) -> ([i64; 3], [[i64; 3]; 3]) {
+   let pos = s.mat.position();
+   let rot = [0, 1, 2].map(|r| [0, 1, 2].map(|c| s.mat.rows[r].data[c]));
+   ([pos.data[0], pos.data[1], pos.data[2]], rot)
+}
+
+fn join<T: ToString>(vals: &[T]) -> String {
+   vals.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+// ASCII PLY. Beacons (white) and scanners (red) are both vertices, so they
+// show up anywhere; each has the scanners that saw it (or itself). The
+// `scanner` element has the full poses.
+fn ply(assembly: &Assembly) -> String {
+   let beacons = beacon_map(&assembly.scanners, 0);
+   let mut ret = String::new();
+   ret += "ply\nformat ascii 1.0\n";
+   ret += "comment Beacons are white and scanners red.\n";
+   if !assembly.unplaced.is_empty() {
+      writeln!(ret, "comment Unplaced scanners: {}",
+               join(&assembly.unplaced)).unwrap();
+   }
+   writeln!(ret, "element vertex {}",
+            beacons.len() + assembly.scanners.len()).unwrap();
+   for prop in ["int x", "int y", "int z", "uchar red", "uchar green",
+                "uchar blue", "list uchar int scanners"] {
+      writeln!(ret, "property {}", prop).unwrap();
+   }
+   writeln!(ret, "element scanner {}", assembly.scanners.len()).unwrap();
+   ret += "property int id\nproperty int x\nproperty int y\nproperty int z\n";
+   for r in 0..3 {
+      for c in 0..3 {
+         writeln!(ret, "property int r{}{}", r, c).unwrap();
+      }
+   }
+   ret += "end_header\n";
+
+   for (p, seen_by) in beacons.iter() {
+      writeln!(ret, "{} {} {} 255 255 255 {} {}", p.data[0], p.data[1],
+               p.data[2], seen_by.len(),
+               seen_by.iter().map(|id| id.to_string())
+                  .collect::<Vec<_>>().join(" ")).unwrap();
+   }
+   for s in assembly.scanners.iter() {
+      let (pos, _) = pose(s);
+      writeln!(ret, "{} {} {} 255 0 0 1 {}", pos[0], pos[1], pos[2],
+               s.id).unwrap();
+   }
+   for s in assembly.scanners.iter() {
+      let (pos, rot) = pose(s);
+      let rot: Vec<String> = rot.iter().flatten().map(|v| v.to_string())
+         .collect();
+      writeln!(ret, "{} {} {} {} {}", s.id, pos[0], pos[1], pos[2],
+               rot.join(" ")).unwrap();
+   }
+   ret
+}
+
+// {"scanners": [{"id": 0, "position": [0, 0, 0],
+//                "rotation": [[1, 0, 0], [0, 1, 0], [0, 0, 1]]}, ...],
+//  "unplaced": [],
+//  "beacons": [{"position": [-892, 524, 684], "seen_by": [0, 1]}, ...]}
+fn json(assembly: &Assembly) -> String {
+   let mut ret = String::new();
+   ret += "{\n  \"scanners\": [";
+   for (i, s) in assembly.scanners.iter().enumerate() {
+      let (pos, rot) = pose(s);
+      let rot: Vec<String> = rot.iter()
+         .map(|row| format!("[{}]", join(row))).collect();
+      write!(ret, "{}\n    {{\"id\": {}, \"position\": [{}], \
+                   \"rotation\": [{}]}}",
+             if i == 0 { "" } else { "," }, s.id, join(&pos),
+             rot.join(", ")).unwrap();
+   }
+   ret += "\n  ],\n";
+   writeln!(ret, "  \"unplaced\": [{}],", join(&assembly.unplaced)).unwrap();
+   ret += "  \"beacons\": [";
+   let beacons = beacon_map(&assembly.scanners, 0);
+   for (i, (p, seen_by)) in beacons.iter().enumerate() {
+      write!(ret, "{}\n    {{\"position\": [{}], \"seen_by\": [{}]}}",
+             if i == 0 { "" } else { "," }, join(&p.data[..3]),
+             join(seen_by)).unwrap();
+   }
+   ret += "\n  ]\n}\n";
+   ret
+}
+
+// Graphviz: every overlap, labelled with how many beacons it lines up. Those
+// that placed a scanner are bold, any that disagree with them red, and
+// scanners left out dashed.
+fn dot(assembly: &Assembly) -> String {
+   let mut ret = String::new();
+   ret += "graph scanners {\n";
+   for (i, id) in assembly.graph.ids.iter().enumerate() {
+      let style = if i == 0 {
+         " [shape=doublecircle]"
+      } else if assembly.unplaced.contains(id) {
+         " [style=dashed]"
+      } else {
+         ""
+      };
+      writeln!(ret, "  {}{};", id, style).unwrap();
+   }
+   for (i, o) in assembly.graph.overlaps.iter().enumerate() {
+      let check = assembly.checks.iter().find(|c| c.overlap == i);
+      let style = match check {
+         _ if assembly.tree.contains(&i) => ", penwidth=3",
+         Some(c) if !c.consistent => ", color=red",
+         _ => "",
+      };
+      writeln!(ret, "  {} -- {} [label={}{}];", o.a, o.b, o.beacons,
+               style).unwrap();
+   }
+   ret += "}\n";
+   ret
+}
+
+// For `aoc export`.
+pub fn export(input: &str, format: &str)
+      -> std::result::Result<String, String> {
+   let scans = Day19::parse(input).map_err(|e| e.to_string())?;
+   let assembly = solve(&scans, &Params::default());
+   match format {
+      "ply" => Ok(ply(&assembly)),
+      "json" => Ok(json(&assembly)),
+      "dot" => Ok(dot(&assembly)),
+      _ => Err(format!("Day 19 exports ply, json or dot, not {:?}.",
+                       format)),
+   }
+}
+
+// -
+
+#[test]
+fn test_export() {
+   let scans = Day19::parse(fixture!("example.txt")).unwrap();
+   let assembly = solve(&scans, &Params::default());
+
+   let ply = ply(&assembly);
+   let (header, body) = ply.split_once("end_header\n").unwrap();
+   assert!(header.starts_with("ply\nformat ascii 1.0\n"));
+   assert!(header.contains("element vertex 84\n"));
+   assert!(header.contains("element scanner 5\n"));
+   let lines: Vec<&str> = body.lines().collect();
+   assert_eq!(lines.len(), 84 + 5);
+   // "-618,-824,-621" is seen by scanners 0 and 1.
+   assert!(lines.contains(&"-618 -824 -621 255 255 255 2 0 1"));
+   assert!(lines.contains(&"68 -1246 -43 255 0 0 1 1"));
+   assert_eq!(lines[84], "0 0 0 0 1 0 0 0 1 0 0 0 1");
+
+   let json = json(&assembly);
+   assert!(json.contains("{\"id\": 0, \"position\": [0, 0, 0], \
+                          \"rotation\": [[1, 0, 0], [0, 1, 0], [0, 0, 1]]}"));
+   assert!(json.contains("\"position\": [1105, -1205, 1229]"));
+   assert!(json.contains(
+      "{\"position\": [-618, -824, -621], \"seen_by\": [0, 1]}"));
+   assert!(json.contains("\"unplaced\": [],"));
+   assert_eq!(json.matches("\"seen_by\"").count(), 79);
+
+   let dot = dot(&assembly);
+   assert!(dot.starts_with("graph scanners {\n  0 [shape=doublecircle];\n"));
+   assert!(dot.contains("\n  1 -- 3 [label=12, penwidth=3];\n"));
+   assert_eq!(dot.matches(" -- ").count(), 4);
+
+   assert!(export(fixture!("example.txt"), "obj").is_err());
+}
@@ -0,0 +1,119 @@
+// Random scanner reports, from known scanner poses.
+
+use std::collections::HashSet;
+
+use aoc_common::Rng;
+
+use super::*;
+
+// -
+
+// Scanners see beacons at most this far away along each axis.
+const RANGE: i64 = 1000;
+// Beacons scattered around each scanner, besides the overlaps.
+const SCATTERED: usize = 20;
+// Each scanner overlaps the one it was placed next to by at least this many.
+const OVERLAP: usize = 12;
+
+pub struct Case {
+   pub input: String,
+   // Where each scanner really is, relative to scanner 0.
+   pub positions: Vec<[i64; 3]>,
+   pub beacons: usize,
+   pub max_distance: i64,
+}
+
+fn sees(pos: &[i64; 3], beacon: &[i64; 3]) -> bool {
+   (0..3).all(|i| (beacon[i] - pos[i]).abs() <= RANGE)
+}
+
+fn point_in(rng: &mut Rng, lo: [i64; 3], hi: [i64; 3]) -> [i64; 3] {
+   [rng.range(lo[0]..=hi[0]), rng.range(lo[1]..=hi[1]),
+    rng.range(lo[2]..=hi[2])]
+}
+
+// `size` scanners, each placed near an earlier one. Scanner 0 is at the
+// origin, facing the way every answer is measured in.
+pub fn case(rng: &mut Rng, size: usize) -> Case {
+   let size = size.max(1);
+   let mut positions = vec![[0, 0, 0]];
+   let mut parents = vec![0];
+   for k in 1..size {
+      let parent = rng.below(k as u64) as usize;
+      let p = positions[parent];
+      let near = point_in(rng, [p[0] - 1200, p[1] - 1200, p[2] - 1200],
+                          [p[0] + 1200, p[1] + 1200, p[2] + 1200]);
+      positions.push(near);
+      parents.push(parent);
+   }
+
+   let mut beacons = HashSet::new();
+   for p in positions.iter() {
+      for _ in 0..SCATTERED {
+         beacons.insert(point_in(rng, [p[0] - RANGE, p[1] - RANGE, p[2] - RANGE],
+                                 [p[0] + RANGE, p[1] + RANGE, p[2] + RANGE]));
+      }
+   }
+   for k in 1..size {
+      let (a, b) = (positions[k], positions[parents[k]]);
+      let lo = [0, 1, 2].map(|i| a[i].max(b[i]) - RANGE);
+      let hi = [0, 1, 2].map(|i| a[i].min(b[i]) + RANGE);
+      while beacons.iter().filter(|x| sees(&a, x) && sees(&b, x)).count()
+            < OVERLAP {
+         beacons.insert(point_in(rng, lo, hi));
+      }
+   }
+
+   let mut input = String::new();
+   let rotations = Mat44::rotations(3);
+   for (k, pos) in positions.iter().enumerate() {
+      let rot = if k == 0 { Mat44::identity() } else { *rng.pick(&rotations) };
+      // Rotations are orthonormal, so the transpose undoes them.
+      let unrot = rot.transpose();
+      let mut seen: Vec<&[i64; 3]> = beacons.iter()
+         .filter(|b| sees(pos, b)).collect();
+      seen.sort_unstable();
+      rng.shuffle(&mut seen);
+      input += &format!("--- scanner {} ---\n", k);
+      for b in seen {
+         let rel = Vec4::new([b[0] - pos[0], b[1] - pos[1], b[2] - pos[2], 1]);
+         let p = unrot * rel;
+         input += &format!("{},{},{}\n", p.data[0], p.data[1], p.data[2]);
+      }
+      input.push('\n');
+   }
+
+   let mut max_distance = 0;
+   for a in positions.iter() {
+      for b in positions.iter() {
+         let dist = (0..3).map(|i| (a[i] - b[i]).abs()).sum();
+         max_distance = cmp::max(max_distance, dist);
+      }
+   }
+   Case{input, positions, beacons: beacons.len(), max_distance}
+}
+
+pub fn input(rng: &mut Rng, size: usize) -> String {
+   case(rng, size).input
+}
+
+// -
+
+#[test]
+fn test_gen() {
+   for seed in 0..10 {
+      let mut rng = Rng::new(seed);
+      let case = case(&mut rng, 1 + seed as usize % 6);
+      let scans = Day19::parse(&case.input).unwrap();
+      let mut found: Vec<[i64; 3]> = solve_all(&scans).iter().map(|s| {
+         let p = s.mat.position();
+         [p.data[0], p.data[1], p.data[2]]
+      }).collect();
+      found.sort_unstable();
+      let mut positions = case.positions.clone();
+      positions.sort_unstable();
+      assert_eq!(found, positions, "{}", case.input);
+      assert_eq!(Day19::part1(&scans), case.beacons);
+      assert_eq!(Day19::part2(&scans), case.max_distance);
+   }
+}
@@ -0,0 +1,176 @@
+// Every overlap between two scanners, as a graph. The scanners are placed
+// along its strongest overlaps, and the rest are checked against that.
+
+use super::*;
+
+// -
+
+// Scanners `a` and `b` see `beacons` in common, and `mat` turns what `b`
+// sees into what `a` does.
+pub struct Overlap {
+   pub a: u64,
+   pub b: u64,
+   pub beacons: usize,
+   pub mat: Mat44,
+}
+
+pub struct Graph {
+   // Sorted.
+   pub ids: Vec<u64>,
+   pub overlaps: Vec<Overlap>,
+}
+
+// Where an overlap outside the tree puts one of its scanners, relative to
+// where the tree put it.
+pub struct Check {
+   // Into `Graph::overlaps`.
+   pub overlap: usize,
+   // Facing another way entirely.
+   pub turned: bool,
+   // Off by this much along some axis.
+   pub drift: i64,
+   pub consistent: bool,
+}
+
+impl Graph {
+   // Tries each pair of scanners whose fingerprints might overlap.
+   pub fn new(scans: &[AdriftScanner], params: &Params) -> Graph {
+      let prints: Vec<Fingerprint> = scans.iter()
+         .map(|s| s.fingerprint()).collect();
+      let mut overlaps = Vec::new();
+      for (i, a) in scans.iter().enumerate() {
+         let known = a.clone().fix(&Mat44::identity());
+         for (j, b) in scans.iter().enumerate().skip(i + 1) {
+            let shared = prints[i].shared(&prints[j], params.slack());
+            if shared < shared_dists(params.overlap) {
+               continue;
+            }
+            debug!("Trying {},{}", a.id, b.id);
+            let found = find_mat(&known, &prints[i], b, &prints[j], params);
+            if let Some((beacons, mat)) = found {
+               debug!("  {} in common", beacons);
+               overlaps.push(Overlap{a: a.id, b: b.id, beacons, mat});
+            }
+         }
+      }
+      let mut ids: Vec<u64> = scans.iter().map(|s| s.id).collect();
+      ids.sort_unstable();
+      Graph{ids, overlaps}
+   }
+
+   // A maximum spanning tree, grown from the first scanner (usually 0):
+   // each scanner joins by its strongest overlap with one already placed.
+   // Returns where each is, relative to the first, and the overlaps used.
+   pub fn spanning_tree(&self) -> (HashMap<u64, Mat44>, Vec<usize>) {
+      let mut poses = HashMap::new();
+      let mut tree = Vec::new();
+      let root = match self.ids.first() {
+         Some(&id) => id,
+         None => return (poses, tree),
+      };
+      poses.insert(root, Mat44::identity());
+      loop {
+         // The earliest of the strongest, so ties always go the same way.
+         let next = self.overlaps.iter().enumerate()
+            .filter(|(_, o)| poses.contains_key(&o.a)
+                             != poses.contains_key(&o.b))
+            .max_by_key(|&(i, o)| (o.beacons, cmp::Reverse(i)));
+         let (i, o) = match next {
+            Some(next) => next,
+            None => break,
+         };
+         match poses.get(&o.a) {
+            Some(&a) => poses.insert(o.b, a * o.mat),
+            None => poses.insert(o.a, poses[&o.b] * o.mat.rigid_inverse()),
+         };
+         tree.push(i);
+      }
+      (poses, tree)
+   }
+
+   // Each overlap outside the tree, between two placed scanners, gives a
+   // second opinion of where one is. Without noise the two should agree
+   // exactly; with it, within the slop of each overlap around the loop.
+   pub fn checks(&self, poses: &HashMap<u64, Mat44>, tree: &[usize],
+                 params: &Params) -> Vec<Check> {
+      let depths = self.depths(tree);
+      self.overlaps.iter().enumerate().filter(|(i, _)| !tree.contains(i))
+         .filter_map(|(i, o)| {
+            let (a, b) = (poses.get(&o.a)?, poses.get(&o.b)?);
+            // Back to the identity, if they agree.
+            let diff = b.rigid_inverse() * *a * o.mat;
+            let identity = Mat44::identity();
+            let turned = (0..3)
+               .any(|r| diff[r].data[..3] != identity[r].data[..3]);
+            let drift = (0..3).map(|r| diff[r][3].abs()).max().unwrap();
+            let hops = (depths[&o.a] + depths[&o.b] + 1) as i64;
+            let consistent = !turned && drift <= hops * params.slop();
+            Some(Check{overlap: i, turned, drift, consistent})
+         }).collect()
+   }
+
+   // How many overlaps each placed scanner is from the first.
+   fn depths(&self, tree: &[usize]) -> HashMap<u64, usize> {
+      let mut ret = HashMap::new();
+      if let Some(&root) = self.ids.first() {
+         ret.insert(root, 0);
+      }
+      // Each tree overlap joins a scanner to one placed before it.
+      for &i in tree.iter() {
+         let o = &self.overlaps[i];
+         match ret.get(&o.a) {
+            Some(&d) => ret.insert(o.b, d + 1),
+            None => ret.insert(o.a, ret[&o.b] + 1),
+         };
+      }
+      ret
+   }
+}
+
+// -
+
+#[test]
+fn test_graph() {
+   let scans = Day19::parse(fixture!("example.txt")).unwrap();
+   let params = Params::default();
+   let graph = Graph::new(&scans, &params);
+   let pairs: Vec<(u64, u64, usize)> = graph.overlaps.iter()
+      .map(|o| (o.a, o.b, o.beacons)).collect();
+   assert_eq!(pairs, [(0, 1, 12), (1, 3, 12), (1, 4, 12), (2, 4, 12)]);
+
+   // "scanner 1 must be at 68,-1246,-43 (relative to scanner 0)"
+   let (poses, tree) = graph.spanning_tree();
+   assert_eq!(tree, [0, 1, 2, 3]);
+   assert_eq!(poses[&1].position(), Vector::new([68, -1246, -43, 1]));
+   assert_eq!(poses[&2].position(), Vector::new([1105, -1205, 1229, 1]));
+   assert_eq!(poses[&3].position(), Vector::new([-92, -2380, -20, 1]));
+   assert_eq!(poses[&4].position(), Vector::new([-20, -1133, 1061, 1]));
+   // A tree, so nothing else to check.
+   assert!(graph.checks(&poses, &tree, &params).is_empty());
+
+   // Scanner 4 again, by another name: it overlaps 1, 2 and 4 (fully),
+   // closing loops that the tree leaves out.
+   let four = fixture!("example.txt").split("\n\n").nth(4).unwrap();
+   let input = format!("{}\n\n{}", fixture!("example.txt").trim(),
+                       four.replace("scanner 4", "scanner 5"));
+   let scans = Day19::parse(&input).unwrap();
+   let graph = Graph::new(&scans, &params);
+   let (poses, tree) = graph.spanning_tree();
+   assert_eq!(poses[&5], poses[&4]);
+   // 4 and 5 are joined by everything 4 sees, and the rest agree.
+   let joined = tree.iter().map(|&i| &graph.overlaps[i])
+      .find(|o| o.b == 5).unwrap();
+   assert_eq!((joined.a, joined.beacons), (4, scans[4].pings.len()));
+   let checks = graph.checks(&poses, &tree, &params);
+   assert_eq!(checks.len(), 2);
+   assert!(checks.iter().all(|c| c.consistent && c.drift == 0));
+
+   // A bad overlap is caught.
+   let mut graph = graph;
+   let i = checks[0].overlap;
+   let nudge = Mat44::translation(&Vector::new([3, 0, 0, 0]));
+   graph.overlaps[i].mat = nudge * graph.overlaps[i].mat;
+   let checks = graph.checks(&poses, &tree, &params);
+   assert_eq!((checks[0].drift, checks[0].consistent), (3, false));
+   assert!(checks[1].consistent);
+}
@@ -0,0 +1,532 @@
+use std::collections::HashMap;
+use std::cmp;
+use log::{debug, warn};
+use aoc_common::{Matrix, Result, Solution, Source, Vector};
+#[cfg(test)]
+use aoc_common::fixture;
+#[cfg(test)]
+use std::collections::HashSet;
+
+pub mod export;
+pub mod gen;
+mod graph;
+
+use graph::{Check, Graph};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+   type Input = Vec<AdriftScanner>;
+   type Answer1 = usize;
+   type Answer2 = i64;
+   const DAY: u32 = 19;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse_input(input)
+   }
+   fn part1(scans: &Self::Input) -> usize {
+      part1(scans)
+   }
+   fn part2(scans: &Self::Input) -> i64 {
+      part2(scans)
+   }
+}
+
+// -
+
+// Homogeneous 3D: points end in 1, offsets in 0.
+type Vec4 = Vector<4>;
+type Mat44 = Matrix<4>;
+
+// -
+
+#[derive(Clone)]
+pub struct AdriftScanner {
+   id: u64,
+   pings: Vec<Vec4>,
+}
+
+struct Scanner {
+   id: u64,
+   mat: Mat44,
+   visible_beacons: Vec<Vec4>,
+}
+
+// Squared distances between each two of a scanner's beacons, with the two
+// beacons, shortest first. However the scanner's turned or moved, these
+// stay the same.
+struct Fingerprint {
+   dists: Vec<(i64, usize, usize)>,
+}
+impl Fingerprint {
+   fn dist(&self, n: usize) -> f64 {
+      (self.dists[n].0 as f64).sqrt()
+   }
+
+   // Distances in common, each used once, within `slack`.
+   fn shared(&self, other: &Fingerprint, slack: f64) -> usize {
+      let mut ret = 0;
+      let (mut a, mut b) = (0, 0);
+      while a < self.dists.len() && b < other.dists.len() {
+         let (da, db) = (self.dist(a), other.dist(b));
+         if (da - db).abs() <= slack {
+            ret += 1;
+            a += 1;
+            b += 1;
+         } else if da < db {
+            a += 1;
+         } else {
+            b += 1;
+         }
+      }
+      ret
+   }
+
+   // Every pair of beacon pairs, one from each, whose distances are within
+   // `slack`: closest first, since those are likeliest to be the same two.
+   fn candidates(&self, other: &Fingerprint, slack: f64)
+         -> Vec<((usize,usize), (usize,usize))> {
+      let mut ret = Vec::new();
+      let mut lo = 0;
+      for a in 0..self.dists.len() {
+         let da = self.dist(a);
+         while lo < other.dists.len() && other.dist(lo) < da - slack {
+            lo += 1;
+         }
+         for b in lo..other.dists.len() {
+            let db = other.dist(b);
+            if db > da + slack {
+               break;
+            }
+            let (_, i, j) = self.dists[a];
+            let (_, k, l) = other.dists[b];
+            ret.push(((da - db).abs(), (i, j), (k, l)));
+         }
+      }
+      ret.sort_by(|x, y| x.0.total_cmp(&y.0));
+      ret.into_iter().map(|(_, a, b)| (a, b)).collect()
+   }
+}
+
+impl AdriftScanner {
+   fn fingerprint(&self) -> Fingerprint {
+      let mut dists = Vec::new();
+      for (i, a) in self.pings.iter().enumerate() {
+         for (j, b) in self.pings.iter().enumerate().skip(i + 1) {
+            let v = *b - *a;
+            dists.push((v.dot(&v), i, j));
+         }
+      }
+      dists.sort_unstable();
+      Fingerprint{dists}
+   }
+
+   fn fix(self, rectify_mat: &Mat44) -> Scanner {
+      Scanner{
+         id: self.id,
+         mat: *rectify_mat,
+         visible_beacons: self.pings.into_iter().map(|p| {
+            *rectify_mat * p
+         }).collect(),
+      }
+   }
+}
+
+// -
+
+fn parse_scan(src: &Source, mut s: &str) -> Result<AdriftScanner> {
+   s = s.trim_start();
+
+   // "--- scanner 26 ---"
+   s = src.strip_prefix(s, "--- scanner ")?;
+   let (id_s, s) = src.split_once(s, " ")?;
+   let id: u64 = src.parse(id_s, "a scanner number")?;
+   let (_, s) = src.split_once(s, "\n")?;
+
+   // "-7,43,-97"
+   let pings = s.lines().map(|line| {
+      let vals = line.split(',').map(|s| src.parse(s, "a coordinate"))
+         .collect::<Result<Vec<i64>>>()?;
+      if vals.len() != 3 {
+         return Err(src.error(line, "3 coordinates"));
+      }
+      let mut p = Vec4::origin();
+      p.data[0] = vals[0];
+      p.data[1] = vals[1];
+      p.data[2] = vals[2];
+      Ok(p)
+   }).collect::<Result<_>>()?;
+
+   Ok(AdriftScanner{id,
+      pings,
+   })
+}
+
+fn parse_input(input: &str) -> Result<Vec<AdriftScanner>> {
+   let src = Source::new(input);
+   input.trim().split("\n\n").map(|single_scan| {
+      parse_scan(&src, single_scan)
+   }).collect()
+}
+
+#[derive(Clone,Copy,Debug)]
+pub struct Params {
+   // Scanners overlap if they see at least this many beacons in common.
+   pub overlap: usize,
+   // How far off (along each axis) any coordinate might be. 0 for exact.
+   pub tolerance: i64,
+   // With noise, transforms to try for each pair of scanners, keeping the
+   // one that lines up the most beacons. (Exact matching tries them all.)
+   pub tries: usize,
+}
+impl Default for Params {
+   // The puzzle's: exact, and 12 beacons.
+   fn default() -> Params {
+      Params{overlap: 12, tolerance: 0, tries: 20}
+   }
+}
+impl Params {
+   // However far off each beacon is, two sightings of it are at most this
+   // far apart along each axis.
+   fn slop(&self) -> i64 {
+      2 * self.tolerance
+   }
+   // ...and so the distance between two beacons, as two scanners see it,
+   // differs by at most this much.
+   fn slack(&self) -> f64 {
+      2.0 * 3f64.sqrt() * self.slop() as f64
+   }
+}
+
+// Points, bucketed so that those near any point are quick to find.
+struct Cloud {
+   // Within `slop` along each axis is near.
+   slop: i64,
+   cells: HashMap<[i64; 3], Vec<Vec4>>,
+}
+impl Cloud {
+   fn new(slop: i64) -> Cloud {
+      Cloud{slop, cells: HashMap::new()}
+   }
+   fn cell(&self, p: &Vec4) -> [i64; 3] {
+      let size = cmp::max(1, self.slop);
+      [0, 1, 2].map(|i| p.data[i].div_euclid(size))
+   }
+   fn insert(&mut self, p: Vec4) {
+      self.cells.entry(self.cell(&p)).or_default().push(p);
+   }
+   fn nearest(&self, p: &Vec4) -> Option<&Vec4> {
+      let c = self.cell(p);
+      let reach = if self.slop == 0 { 0 } else { 1 };
+      let mut best: Option<(i64, &Vec4)> = None;
+      for dx in -reach..=reach {
+         for dy in -reach..=reach {
+            for dz in -reach..=reach {
+               let cell = [c[0] + dx, c[1] + dy, c[2] + dz];
+               for q in self.cells.get(&cell).into_iter().flatten() {
+                  let off = (0..3).map(|i| (q.data[i] - p.data[i]).abs())
+                     .max().unwrap();
+                  if off <= self.slop && best.is_none_or(|(b, _)| off < b) {
+                     best = Some((off, q));
+                  }
+               }
+            }
+         }
+      }
+      best.map(|(_, q)| q)
+   }
+}
+
+// For a scanner to overlap another by `overlap` beacons, they need this
+// many distances in common.
+fn shared_dists(overlap: usize) -> usize {
+   overlap * overlap.saturating_sub(1) / 2
+}
+
+// The adrift beacons that `mat` lines up with known ones, with those.
+fn inliers<'a>(mat: &Mat44, known: &'a Cloud, adrift: &AdriftScanner)
+      -> Vec<(Vec4, &'a Vec4)> {
+   adrift.pings.iter().filter_map(|p| {
+      let p = *mat * *p;
+      known.nearest(&p).map(|q| (p, q))
+   }).collect()
+}
+
+// Matching distances are probably the same two beacons. Turning the adrift
+// pair to line up with the known one gives a rotation, and then either end
+// gives an offset. Of the transforms that gives, the one that lines up the
+// most beacons wins (RANSAC-style), as long as that's at least `overlap`.
+// With noise, the offset is then nudged to the middle of the inliers.
+// Returns how many beacons line up, with the transform.
+fn find_mat(known: &Scanner, known_print: &Fingerprint,
+            adrift: &AdriftScanner, adrift_print: &Fingerprint,
+            params: &Params) -> Option<(usize, Mat44)> {
+   let slop = params.slop();
+   let mut known_beacons = Cloud::new(2 * slop);
+   for p in known.visible_beacons.iter() {
+      known_beacons.insert(*p);
+   }
+   let close = |a: &Vec4, b: &Vec4| {
+      (0..3).all(|i| (a.data[i] - b.data[i]).abs() <= 2 * slop)
+   };
+   let candidates = known_print.candidates(adrift_print, params.slack());
+   let dir_mats = Mat44::rotations(3);
+
+   let mut best: Option<(usize, Mat44)> = None;
+   let mut tries = 0;
+   'candidates: for ((i, j), (k, l)) in candidates {
+      // Exact matches keep looking until one lines up (below).
+      if slop > 0 && tries >= params.tries {
+         break;
+      }
+      let v_known = known.visible_beacons[j] - known.visible_beacons[i];
+      let v_adrift = adrift.pings[l] - adrift.pings[k];
+      for dir_mat in dir_mats.iter() {
+         let v_guess = *dir_mat * v_adrift;
+         // Whether k is i or j.
+         let anchor = if close(&v_guess, &v_known) {
+            i
+         } else if close(&v_guess, &-v_known) {
+            j
+         } else {
+            continue;
+         };
+         let p_guess = *dir_mat * adrift.pings[k];
+         let offset = known.visible_beacons[anchor] - p_guess;
+         let rectify_mat = Mat44::translation(&offset) * *dir_mat;
+         let hits = inliers(&rectify_mat, &known_beacons, adrift).len();
+         tries += 1;
+         if best.is_none_or(|(most, _)| hits > most) {
+            best = Some((hits, rectify_mat));
+         }
+         // Exactly lining up that many is no accident.
+         if slop == 0 && hits >= params.overlap {
+            break 'candidates;
+         }
+      }
+   }
+   let (hits, mut rectify_mat) = best?;
+   if hits < params.overlap {
+      return None;
+   }
+   if slop > 0 {
+      let pairs = inliers(&rectify_mat, &known_beacons, adrift);
+      let mut nudge = Vec4::zero();
+      for i in 0..3 {
+         let mut offs: Vec<i64> = pairs.iter()
+            .map(|(p, q)| q.data[i] - p.data[i]).collect();
+         offs.sort_unstable();
+         nudge.data[i] = offs[offs.len() / 2];
+      }
+      rectify_mat = Mat44::translation(&nudge) * rectify_mat;
+   }
+   Some((hits, rectify_mat))
+}
+
+// -
+
+struct Assembly {
+   // Relative to the first scanner.
+   scanners: Vec<Scanner>,
+   // Those that didn't overlap enough with any of the others.
+   unplaced: Vec<u64>,
+   graph: Graph,
+   // The overlaps that placed each scanner, into `graph.overlaps`.
+   tree: Vec<usize>,
+   // The other overlaps, against where the tree placed things.
+   checks: Vec<Check>,
+}
+
+// Every scanner's overlaps are found first, and then each is placed by the
+// strongest path to the first (usually 0), rather than by whichever
+// happened to be found first.
+fn solve(scans: &[AdriftScanner], params: &Params) -> Assembly {
+   let graph = Graph::new(scans, params);
+   let (poses, tree) = graph.spanning_tree();
+   let checks = graph.checks(&poses, &tree, params);
+
+   let mut scanners = Vec::new();
+   let mut unplaced = Vec::new();
+   for scan in scans.iter() {
+      match poses.get(&scan.id) {
+         Some(mat) => scanners.push(scan.clone().fix(mat)),
+         None => unplaced.push(scan.id),
+      }
+   }
+   scanners.sort_unstable_by_key(|s| s.id);
+   unplaced.sort_unstable();
+   Assembly{scanners, unplaced, graph, tree, checks}
+}
+
+// Placed with the puzzle's params, where everything should be.
+fn solve_all(scans: &[AdriftScanner]) -> Vec<Scanner> {
+   let assembly = solve(scans, &Params::default());
+   if !assembly.unplaced.is_empty() {
+      warn!("Couldn't place scanners {:?}", assembly.unplaced);
+   }
+   for check in assembly.checks.iter().filter(|c| !c.consistent) {
+      let o = &assembly.graph.overlaps[check.overlap];
+      warn!("Scanners {} and {} disagree with the rest (turned: {}, \
+             drift: {})", o.a, o.b, check.turned, check.drift);
+   }
+   assembly.scanners
+}
+
+// Every beacon, with the scanners that saw it. Sightings within `slop` of
+// each other are the same beacon.
+fn beacon_map(scanners: &[Scanner], slop: i64) -> Vec<(Vec4, Vec<u64>)> {
+   let mut cloud = Cloud::new(slop);
+   let mut index: HashMap<Vec4, usize> = HashMap::new();
+   let mut ret: Vec<(Vec4, Vec<u64>)> = Vec::new();
+   for s in scanners.iter() {
+      for p in s.visible_beacons.iter() {
+         let i = match cloud.nearest(p) {
+            Some(q) => index[q],
+            None => {
+               cloud.insert(*p);
+               index.insert(*p, ret.len());
+               ret.push((*p, Vec::new()));
+               ret.len() - 1
+            },
+         };
+         if !ret[i].1.contains(&s.id) {
+            ret[i].1.push(s.id);
+         }
+      }
+   }
+   ret
+}
+
+fn all_beacons(scanners: &[Scanner], slop: i64) -> Vec<Vec4> {
+   beacon_map(scanners, slop).into_iter().map(|(p, _)| p).collect()
+}
+
+// "Assemble the full map of beacons. How many beacons are there?"
+fn part1(scans: &[AdriftScanner]) -> usize {
+   all_beacons(&solve_all(scans), 0).len()
+}
+
+// "What is the largest Manhattan distance between any two scanners?"
+fn part2(scans: &[AdriftScanner]) -> i64 {
+   let scanners = solve_all(scans);
+   let iter = scanners.iter();
+   let mut max_dist = 0;
+   for a in iter.clone() {
+      for b in iter.clone().skip(1) {
+         let dist = (b.mat.position() - a.mat.position()).manhattan();
+         max_dist = cmp::max(max_dist, dist);
+      }
+   }
+   max_dist
+}
+
+// -
+
+#[test]
+fn test_example() {
+   let input = fixture!("example.txt");
+   let actual_beacon_list = fixture!("example-beacons.txt");
+   let actual_beacons: HashSet<Vec4> = actual_beacon_list.lines().map(|line| {
+      let vals: Vec<i64> = line.split(',').map(|s| s.parse().unwrap()).collect();
+      Vec4::new([vals[0], vals[1], vals[2], 1])
+   }).collect();
+   let scans = Day19::parse(input).unwrap();
+   let beacons = all_beacons(&solve_all(&scans), 0);
+   assert_eq!(beacons.len(), actual_beacons.len());
+   assert_eq!(beacons.into_iter().collect::<HashSet<_>>(), actual_beacons);
+
+   assert_eq!(Day19::part1(&scans), 79);
+   assert_eq!(Day19::part2(&scans), 3621);
+}
+
+#[test]
+fn test_fingerprint() {
+   // "scanners 0 and 1 have overlapping detection cubes", as do 1 and 3,
+   // 1 and 4, and 2 and 4. No others do.
+   let scans = Day19::parse(fixture!("example.txt")).unwrap();
+   let prints: Vec<Fingerprint> = scans.iter()
+      .map(|s| s.fingerprint()).collect();
+   let overlaps = [(0, 1), (1, 3), (1, 4), (2, 4)];
+   for a in 0..scans.len() {
+      for b in a+1..scans.len() {
+         let shared = prints[a].shared(&prints[b], 0.0);
+         assert_eq!(shared >= shared_dists(12), overlaps.contains(&(a, b)),
+                    "{} and {} share {}", a, b, shared);
+      }
+   }
+}
+
+#[test]
+fn test_params() {
+   let example = fixture!("example.txt");
+   let scans = Day19::parse(example).unwrap();
+   let ids = |a: &Assembly| a.scanners.iter().map(|s| s.id).collect::<Vec<_>>();
+
+   // The example's overlaps are exactly 12.
+   let strict = solve(&scans, &Params{overlap: 13, ..Params::default()});
+   assert_eq!((ids(&strict), strict.unplaced), (vec![0], vec![1, 2, 3, 4]));
+
+   // A scanner that sees nothing anyone else does is left out.
+   let input = format!("{}\n\n--- scanner 5 ---\n1,2,3\n4,5,6\n",
+                       example.trim());
+   let scans = Day19::parse(&input).unwrap();
+   let assembly = solve(&scans, &Params::default());
+   assert_eq!(assembly.unplaced, [5]);
+   assert_eq!(all_beacons(&assembly.scanners, 0).len(), 79);
+}
+
+#[test]
+fn test_decoys() {
+   // Pairs of beacons, far from everything, the same distances apart in
+   // both scanners, but nowhere near each other once lined up. Being the
+   // shortest distances, they're tried first, and would use up `tries`.
+   let mut scans = Day19::parse(fixture!("example.txt")).unwrap();
+   scans.truncate(2);
+   for k in 1..=25 {
+      let far = 5000 * k;
+      for (scan, at) in scans.iter_mut().zip([[far, 5000, 5000],
+                                              [-5000, -5000, far]]) {
+         let p = Vec4::new([at[0], at[1], at[2], 1]);
+         scan.pings.push(p);
+         scan.pings.push(p + Vec4::new([k, k + 1, k + 3, 0]));
+      }
+   }
+   let assembly = solve(&scans, &Params::default());
+   assert!(assembly.unplaced.is_empty());
+   assert_eq!(assembly.scanners[1].mat.position(),
+              Vec4::new([68, -1246, -43, 1]));
+}
+
+#[test]
+fn test_noise() {
+   use aoc_common::Rng;
+
+   // Every coordinate off by up to 2.
+   let tolerance = 2;
+   let params = Params{tolerance, ..Params::default()};
+   for seed in 0..5 {
+      let mut rng = Rng::new(seed);
+      let case = gen::case(&mut rng, 6);
+      let mut scans = Day19::parse(&case.input).unwrap();
+      for scan in scans.iter_mut() {
+         for p in scan.pings.iter_mut() {
+            for i in 0..3 {
+               p.data[i] += rng.range(-tolerance..=tolerance);
+            }
+         }
+      }
+      assert!(!solve(&scans, &Params::default()).unplaced.is_empty());
+      let assembly = solve(&scans, &params);
+      assert!(assembly.unplaced.is_empty());
+      assert!(assembly.checks.iter().all(|c| c.consistent));
+      for s in assembly.scanners.iter() {
+         let p = s.mat.position();
+         let real = case.positions[s.id as usize];
+         for i in 0..3 {
+            assert!((p.data[i] - real[i]).abs() <= 2 * tolerance,
+                    "seed {}: scanner {} at {:?}, not {:?}",
+                    seed, s.id, p, real);
+         }
+      }
+   }
+}
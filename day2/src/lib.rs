@@ -0,0 +1,84 @@
+use aoc_common::{Result, Solution, Source};
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+   type Input = Vec<(String, i64)>;
+   type Answer1 = i64;
+   type Answer2 = i64;
+   const DAY: u32 = 2;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse_inputs(input)
+   }
+   fn part1(cmds: &Self::Input) -> i64 {
+      part1(cmds)
+   }
+   fn part2(cmds: &Self::Input) -> i64 {
+      part2(cmds)
+   }
+}
+
+// -
+
+fn parse_inputs(input: &str) -> Result<Vec<(String, i64)>> {
+   let src = Source::new(input);
+   src.not_empty("a command")?;
+   input.split('\n')
+      .filter(|x| !x.is_empty())
+      .map(|x| {
+         let (cmd, val_str) = src.split_once(x, " ")?;
+         match cmd {
+            "forward" | "up" | "down" => (),
+            _ => return Err(src.error(cmd, "forward, up or down")),
+         }
+         Ok((cmd.to_string(), src.parse(val_str, "an integer")?))
+      }).collect()
+}
+
+// "What do you get if you multiply your final horizontal position by your
+//  final depth?"
+fn part1(cmds: &[(String, i64)]) -> i64 {
+   let mut x = 0;
+   let mut y = 0;
+   for (cmd, val) in cmds.iter() {
+      match cmd.as_str() {
+         "forward" => x += val,
+         "up" => y -= val,
+         "down" => y += val,
+         _ => panic!("{}", cmd),
+      }
+   }
+   x * y
+}
+
+// Same question, but "up" and "down" now adjust the aim.
+fn part2(cmds: &[(String, i64)]) -> i64 {
+   let mut x = 0;
+   let mut y = 0;
+   let mut aim = 0;
+   for (cmd, val) in cmds.iter() {
+      match cmd.as_str() {
+         "down" => aim += val,
+         "up" => aim -= val,
+         "forward" => {
+            x += val;
+            y += val * aim;
+         },
+         _ => panic!("{}", cmd),
+      }
+   }
+   x * y
+}
+
+// -
+
+#[test]
+fn test_example() {
+   let input = fixture!("example.txt");
+   assert_eq!(Day2::solve1(input), 150);
+   assert_eq!(Day2::solve2(input), 900);
+   assert!(Day2::load("").is_err());
+}
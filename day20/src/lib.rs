@@ -1,12 +1,39 @@
-use std::fs::File;
-use std::io::Read;
-use std::path::Path;
 use std::collections::HashSet;
 use std::cmp;
+use aoc_common::{Result, Solution, Source};
+use log::debug;
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+   // The enhancement algorithm, and the input image.
+   type Input = (BigInt, SparseGrid);
+   type Answer1 = usize;
+   type Answer2 = usize;
+   const DAY: u32 = 20;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse(input)
+   }
+   // "Apply the image enhancement algorithm twice, starting with the
+   //  original input image. How many pixels are lit in the resulting
+   //  image?"
+   fn part1(image: &Self::Input) -> usize {
+      enhance(image, 2)
+   }
+   // "Apply the image enhancement algorithm 50 times."
+   fn part2(image: &Self::Input) -> usize {
+      enhance(image, 50)
+   }
+}
+
+// -
 
-type SparseGrid = HashSet<(i32,i32)>;
+pub type SparseGrid = HashSet<(i32,i32)>;
 
-struct BigInt {
+pub struct BigInt {
    chunks: Vec<u64>,
 }
 impl BigInt {
@@ -33,6 +60,7 @@ impl BigInt {
       let val = self.chunks[chunk_id];
       ((val >> chunk_i) & 1) != 0
    }
+   #[allow(dead_code)]
    fn invert(&self) -> BigInt {
       BigInt {
          chunks: self.chunks.iter().map(|c| !c).collect(),
@@ -40,37 +68,46 @@ impl BigInt {
    }
 }
 
-fn parse(input: &str) -> (BigInt, SparseGrid) {
-   let (lookup, grid_s) = input.split_once("\n\n").unwrap();
+fn parse(input: &str) -> Result<(BigInt, SparseGrid)> {
+   let src = Source::new(input);
+   let (lookup, grid_s) = src.split_once(input, "\n\n")?;
+   let pixel = |line: &str, i: usize, c: char| {
+      match c {
+         '#' => Ok(true),
+         '.' => Ok(false),
+         _ => Err(src.error(&line[i..i+c.len_utf8()], "\"#\" or \".\"")),
+      }
+   };
 
    let bi = {
       let mut bi = BigInt::new();
       let mut i = 0;
-      lookup.trim().lines().for_each(|line| {
-         line.chars().for_each(|c| {
-            let bval = (c == '#');
-            bi.set_bit(&i, bval);
+      for line in lookup.trim().lines() {
+         for (ic,c) in line.char_indices() {
+            bi.set_bit(&i, pixel(line, ic, c)?);
             i += 1;
-         });
-      });
-      assert_eq!(i, 512);
+         }
+      }
+      if i != 512 {
+         return Err(src.error(&lookup[lookup.len()..],
+                              &format!("512 lookup bits, not {}", i)));
+      }
       bi
    };
 
    let grid = {
       let mut grid = SparseGrid::new();
-      grid_s.trim().lines().enumerate().for_each(|(y,line)| {
-         line.chars().enumerate().for_each(|(x,c)| {
-            let bval = (c == '#');
-            if bval {
+      for (y,line) in grid_s.trim().lines().enumerate() {
+         for (x,(ic,c)) in line.char_indices().enumerate() {
+            if pixel(line, ic, c)? {
                grid.insert((x as i32, y as i32));
             }
-         });
-      });
+         }
+      }
       grid
    };
 
-   (bi, grid)
+   Ok((bi, grid))
 }
 
 fn contains_with_offset(grid: &SparseGrid, p: &(i32,i32), o: &(i32,i32)) -> bool {
@@ -97,8 +134,8 @@ const KERNEL_OFFSETS: [(i32, i32); 9] = [
    ( 1, 1),
 ];
 
-fn solve_p1(input: &str, reps: usize) -> usize {
-   let (lookup, mut grid) = parse(input);
+fn enhance((lookup, grid): &(BigInt, SparseGrid), reps: usize) -> usize {
+   let mut grid = grid.clone();
 
    //println!("\n\n\nInitial:");
    //print_bi(&lookup);
@@ -109,29 +146,29 @@ fn solve_p1(input: &str, reps: usize) -> usize {
    let mut bright_val = true;
    let bright_flips = lookup.get_bit(&0);
 
-   for i in 0..reps {
+   for _i in 0..reps {
       let mut next_grid = SparseGrid::new();
       for p in grid.iter() {
          for o in KERNEL_OFFSETS.iter() {
             insert_with_offset(&mut next_grid, p, o);
          }
       }
-      next_grid = next_grid.into_iter().filter(|p| {
+      next_grid.retain(|p| {
          let mut kernel: usize = 0;
          for o in KERNEL_OFFSETS.iter() {
             kernel <<= 1;
-            kernel |= contains_with_offset(&grid, &p, o) as usize;
+            kernel |= contains_with_offset(&grid, p, o) as usize;
          }
-         if bright_val == false {
+         if !bright_val {
             kernel = !kernel & 0b1_1111_1111;
          }
          let mut bval = lookup.get_bit(&kernel);
          //println!("[{}] {:?} -> {} -> {}", i+1, p, kernel, bval);
-         if bright_flips && bright_val == true {
+         if bright_flips && bright_val {
             bval = !bval;
          }
          bval
-      }).collect();
+      });
       grid = next_grid;
       if bright_flips {
          bright_val = !bright_val;
@@ -145,13 +182,14 @@ fn solve_p1(input: &str, reps: usize) -> usize {
 
 // -
 
-
+#[allow(dead_code)]
 fn print_bi(bi: &BigInt) {
    let row: String = (0..(64*bi.chunks.len()))
       .map(|i| if bi.get_bit(&i) { "#" } else { "." }).collect();
-   println!("{}", row);
+   debug!("{}", row);
 }
 
+#[allow(dead_code)]
 fn println(grid: &SparseGrid) {
    let mut min: (i32,i32) = (0,0);
    let mut max: (i32,i32) = (0,0);
@@ -161,7 +199,7 @@ fn println(grid: &SparseGrid) {
       max.0 = cmp::max(max.0, p.0);
       max.1 = cmp::max(max.1, p.1);
    }
-   println!("{:?}..={:?} ({} on)", min, max, grid.len());
+   debug!("{:?}..={:?} ({} on)", min, max, grid.len());
 
    let row: String = (min.0..=max.0).map(|_| '.').collect();
    let mut rows: Vec<String> = (min.1..=max.1).map(|_| row.clone()).collect();
@@ -173,64 +211,21 @@ fn println(grid: &SparseGrid) {
       row.replace_range(x..x+1, "#");
    }
    for row in rows.iter() {
-      println!("{}", row);
+      debug!("{}", row);
    }
 }
 
 // -
 
-//#[test]
+#[test]
 fn test_example() {
-   let input = "\
-..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..##
-#..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###
-.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#.
-.#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#.....
-.#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#..
-...####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.....
-..##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
-
-#..#.
-#....
-##..#
-..#..
-..###
-";
-   assert_eq!(solve_p1(&input,0), 10);
-   assert_eq!(solve_p1(&input,2), 35);
-   assert_eq!(solve_p1(&input,50), 3351);
-
-   let input2 = "\
-#.#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..##
-#..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###
-.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#.
-.#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#.....
-.#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#..
-...####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.....
-..##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
-
-#..#.
-#....
-##..#
-..#..
-..###
-";
-   //assert_eq!(solve_p1(&input2,0), 10);
-   //assert_eq!(solve_p1(&input2,2), 35);
-}
-
-fn main() {
-   test_example();
-   println!("Examples ran clean!");
-
-   let path = Path::new("day20-1/input.txt");
-   let mut file = match File::open(&path) {
-      Err(why) => panic!("File::open({}) -> Err({})", path.display(), why),
-      Ok(file) => file,
-   };
-   let mut input = String::new();
-   file.read_to_string(&mut input).unwrap();
-
-   println!("solve_p1(input,2) -> {}", solve_p1(&input,2));
-   println!("solve_p1(input,50) -> {}", solve_p1(&input,50));
+   let input = fixture!("example.txt");
+   let image = parse(input).unwrap();
+   assert_eq!(enhance(&image, 0), 10);
+   assert_eq!(enhance(&image, 2), 35);
+   assert_eq!(enhance(&image, 50), 3351);
+
+   let input2 = input.replacen('.', "#", 1);
+   // The first lookup bit is lit, so the infinite background flips each step.
+   assert_eq!(enhance(&parse(&input2).unwrap(), 0), 10);
 }
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+use aoc_common::{Result, Solution, Source};
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+   type Input = Game;
+   type Answer1 = i64;
+   type Answer2 = i64;
+   const DAY: u32 = 21;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse(input)
+   }
+   fn part1(game: &Self::Input) -> i64 {
+      part1(game)
+   }
+   fn part2(game: &Self::Input) -> i64 {
+      part2(game)
+   }
+}
+
+// -
+
+#[derive(PartialEq,Eq,Hash,Clone)]
+pub struct Player {
+   pos: i64,
+   score: i64,
+}
+impl Player {
+   fn new(pos: &i64) -> Player {
+      Player {
+         pos: *pos,
+         score: 0,
+      }
+   }
+   fn advance(&mut self, n: &i64) {
+      self.pos += *n;
+      while self.pos >= 11 {
+         self.pos -= 10;
+      }
+      self.score += self.pos;
+   }
+}
+pub type Game = Vec<Player>;
+
+struct Dice {
+   next_val: i64,
+   rolls: i64,
+}
+impl Dice {
+   fn new() -> Dice {
+      Dice{next_val: 1,
+         rolls: 0}
+   }
+   fn roll(&mut self) -> i64 {
+      let ret = self.next_val;
+      self.next_val += 1;
+      while self.next_val >= 101 {
+         self.next_val -= 100;
+      }
+      self.rolls += 1;
+      ret
+   }
+}
+
+// Exactly two players, in order.
+fn parse(input: &str) -> Result<Game> {
+   let src = Source::new(input);
+   let mut lines = input.trim().lines();
+   let mut game = Vec::new();
+   for id in ["1", "2"] {
+      // "Player 1 starting position: 4"
+      let line = lines.next().unwrap_or_else(|| src.end());
+      let line = src.strip_prefix(line, "Player ")?;
+      let (id_s, start_s) = src.split_once(line, " starting position: ")?;
+      if id_s != id {
+         return Err(src.error(id_s, &format!("player {}", id)));
+      }
+      let start_pos: i64 = src.parse(start_s, "a position")?;
+      if !(1..=10).contains(&start_pos) {
+         return Err(src.error(start_s, "a position from 1 to 10"));
+      }
+      game.push(Player::new(&start_pos));
+   }
+   if let Some(extra) = lines.next() {
+      return Err(src.error(extra, "end of input"));
+   }
+   Ok(game)
+}
+
+// "Play a practice game using the deterministic 100-sided die. [...] what do
+//  you get if you multiply the score of the losing player by the number of
+//  times the die was rolled during the game?"
+fn part1(game: &Game) -> i64 {
+   let mut players = game.clone();
+   let mut dice = Dice::new();
+
+   let winner = || -> usize {
+      loop {
+         for (i, p) in players.iter_mut().enumerate() {
+            let roll = dice.roll() + dice.roll() + dice.roll();
+            p.advance(&roll);
+            if p.score >= 1000 {
+               return i;
+            }
+         }
+      }
+   }();
+   players.remove(winner);
+   let loser = &players[0];
+   loser.score * dice.rolls
+}
+
+// "Using your given starting positions, determine every possible outcome.
+//  Find the player that wins in more universes; in how many universes does
+//  that player win?"
+fn part2(game: &Game) -> i64 {
+   let initial_game_state = game.clone();
+
+   let roll_universes_by_val = {
+      let mut all_possibilities: Vec<i64> = vec![0i64];
+      for _ in 0..3 { // three rolls
+         let mut next: Vec<i64> = Vec::new();
+         for prev_rolls in all_possibilities.iter() {
+            for maybe_roll in 1..=3 { // 1 through 3
+               next.push(prev_rolls + maybe_roll);
+            }
+         }
+         all_possibilities = next;
+      }
+      assert_eq!(all_possibilities.len(), 27);
+      let mut count_by_val = HashMap::<i64,i64>::new();
+      all_possibilities.iter().for_each(|val| {
+         let e = count_by_val.entry(*val).or_insert(0);
+         *e += 1;
+      });
+      count_by_val
+   };
+
+   let mut universe_count_by_game_state = HashMap::<Game,i64>::new();
+   universe_count_by_game_state.insert(initial_game_state, 1);
+
+   let wins = {
+      let mut wins: Vec<i64> = vec![0i64; 2];
+      while !universe_count_by_game_state.is_empty() {
+         for cur_player in 0..2 {
+            let mut next_universe_count_by_game_state = HashMap::new();
+
+            for (gstate, game_universes) in universe_count_by_game_state.iter() {
+               for (roll_val, roll_universes) in roll_universes_by_val.iter() {
+                  let mut new_gstate = gstate.clone();
+                  let new_pstate = &mut new_gstate[cur_player];
+                  new_pstate.advance(roll_val);
+                  let new_universes = game_universes * roll_universes;
+                  if new_pstate.score >= 21 {
+                     wins[cur_player] += new_universes;
+                  } else {
+                     let e = next_universe_count_by_game_state.entry(new_gstate).or_insert(0);
+                     *e += new_universes;
+                  }
+               }
+            }
+            universe_count_by_game_state = next_universe_count_by_game_state;
+         }
+      }
+      wins
+   };
+   *wins.iter().max().unwrap()
+}
+
+// -
+
+#[test]
+fn test_example() {
+   let input = fixture!("example.txt");
+   assert_eq!(Day21::solve1(input), 739785);
+   assert_eq!(Day21::solve2(input), 444356092776315);
+
+   let err = |input: &str| Day21::load(input).err().unwrap().to_string();
+   assert_eq!(err(""), "day21: line 1, column 1: expected \"Player \", \
+                        found end of input");
+   assert_eq!(err("Player 1 starting position: 4\n"),
+              "day21: line 2, column 1: expected \"Player \", \
+               found end of input");
+   assert_eq!(err("Player 2 starting position: 4\n\
+                   Player 1 starting position: 8\n"),
+              "day21: line 1, column 8: expected player 1, found \"2\"");
+   assert_eq!(err("Player 1 starting position: 4\n\
+                   Player 2 starting position: 8\n\
+                   Player 3 starting position: 1\n"),
+              "day21: line 3, column 1: expected end of input, \
+               found \"Player 3 starting position: 1\"");
+}
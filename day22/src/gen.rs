@@ -0,0 +1,111 @@
+// Random reboot steps.
+
+use aoc_common::Rng;
+
+// -
+
+// `count` steps, with every coordinate within -extent..=extent.
+pub fn steps(rng: &mut Rng, count: usize, extent: i64) -> String {
+   let mut ret = String::new();
+   for i in 0..count {
+      // Start with something on, or the "off"s have nothing to do.
+      let on = i == 0 || !rng.one_in(3);
+      ret.push_str(if on { "on " } else { "off " });
+      let axes: Vec<String> = ["x", "y", "z"].iter().map(|axis| {
+         let a = rng.range(-extent..=extent);
+         let b = rng.range(-extent..=extent);
+         format!("{}={}..{}", axis, a.min(b), a.max(b))
+      }).collect();
+      ret.push_str(&axes.join(","));
+      ret.push('\n');
+   }
+   ret
+}
+
+// `size` steps, shaped like the real input: the first half within the
+// initialization region, the rest anywhere.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+   let size = size.max(1);
+   let init = size.div_ceil(2);
+   steps(rng, init, 50) + &steps(rng, size - init, 100_000)
+}
+
+// -
+
+// The reference: cut space at every cuboid edge, and flip the cells.
+#[cfg(test)]
+fn reference(input: &str, region: Option<i64>) -> u64 {
+   let mut steps = Vec::new();
+   for line in input.lines() {
+      let (val, line) = line.split_once(' ').unwrap();
+      let mut ranges = line.split(',').map(|axis| {
+         let (first, last) = axis[2..].split_once("..").unwrap();
+         (first.parse::<i64>().unwrap(), last.parse::<i64>().unwrap() + 1)
+      }).collect::<Vec<_>>();
+      if let Some(r) = region {
+         for range in ranges.iter_mut() {
+            *range = (range.0.max(-r), range.1.min(r + 1));
+         }
+         if ranges.iter().any(|&(first, end)| first >= end) {
+            continue;
+         }
+      }
+      steps.push((val == "on", ranges));
+   }
+   if steps.is_empty() {
+      return 0;
+   }
+   let cuts: Vec<Vec<i64>> = (0..3).map(|axis| {
+      let mut cuts: Vec<i64> = steps.iter()
+         .flat_map(|(_, ranges)| [ranges[axis].0, ranges[axis].1])
+         .collect();
+      cuts.sort_unstable();
+      cuts.dedup();
+      cuts
+   }).collect();
+   let index = |axis: usize, v: i64| cuts[axis].binary_search(&v).unwrap();
+   let (nx, ny, nz) = (cuts[0].len(), cuts[1].len(), cuts[2].len());
+   let mut on = vec![false; nx * ny * nz];
+   for (val, ranges) in steps.iter() {
+      for x in index(0, ranges[0].0)..index(0, ranges[0].1) {
+         for y in index(1, ranges[1].0)..index(1, ranges[1].1) {
+            for z in index(2, ranges[2].0)..index(2, ranges[2].1) {
+               on[(x * ny + y) * nz + z] = *val;
+            }
+         }
+      }
+   }
+   let mut count = 0;
+   for x in 0..nx-1 {
+      for y in 0..ny-1 {
+         for z in 0..nz-1 {
+            if on[(x * ny + y) * nz + z] {
+               count += ((cuts[0][x+1] - cuts[0][x]) *
+                         (cuts[1][y+1] - cuts[1][y]) *
+                         (cuts[2][z+1] - cuts[2][z])) as u64;
+            }
+         }
+      }
+   }
+   count
+}
+
+#[test]
+fn test_gen() {
+   use aoc_common::Solution;
+   use crate::Day22;
+
+   for seed in 0..200 {
+      let mut rng = Rng::new(seed);
+      // Small enough that cuboids overlap a lot, and straddle the
+      // initialization region's edge.
+      let input = steps(&mut rng, 1 + seed as usize % 12, 60);
+      let parsed = Day22::parse(&input).unwrap();
+      assert_eq!(Day22::part1(&parsed), reference(&input, Some(50)),
+                 "{}", input);
+      assert_eq!(Day22::part2(&parsed), reference(&input, None), "{}", input);
+   }
+   let input = input(&mut Rng::new(0), 20);
+   let parsed = Day22::parse(&input).unwrap();
+   assert_eq!(Day22::part2(&parsed), reference(&input, None));
+}
@@ -0,0 +1,270 @@
+use std::cmp;
+use std::fmt;
+use std::collections::HashSet;
+use log::info;
+use aoc_common::{Result, Solution, Source, Vector};
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub mod gen;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+   type Input = Vec<Step>;
+   type Answer1 = u64;
+   type Answer2 = u64;
+   const DAY: u32 = 22;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse(input)
+   }
+   // "Execute the reboot steps. Afterward, considering only cubes in the
+   //  region x=-50..50,y=-50..50,z=-50..50, how many cubes are on?"
+   fn part1(steps: &Self::Input) -> u64 {
+      let init_region = Volume{first: Vector::new([-50; 3]),
+                               end: Vector::new([51; 3])};
+      solve(steps, Some(&init_region))
+   }
+   // "Starting again with all cubes off, execute all reboot steps.
+   //  Afterward, considering all cubes, how many cubes are on?"
+   fn part2(steps: &Self::Input) -> u64 {
+      solve(steps, None)
+   }
+}
+
+// -
+
+#[derive(Debug,Clone)]
+pub struct Step {
+   on: bool,
+   vol: Volume,
+}
+fn parse(input: &str) -> Result<Vec<Step>> {
+   let src = Source::new(input);
+   src.not_empty("a reboot step")?;
+   input.trim().lines().map(|line| {
+      // "on x=10..12,y=10..12,z=10..12"
+      let (val, line) = src.split_once(line, " ")?;
+      let on = match val {
+         "on" => true,
+         "off" => false,
+         _ => return Err(src.error(val, "\"on\" or \"off\"")),
+      };
+      let mut coords = line.split(",");
+      let mut vol = Volume{first: Vector::zero(), end: Vector::zero()};
+      for (i, name) in ["x=", "y=", "z="].iter().enumerate() {
+         // "x=10..12" -> 10..13
+         let coord = coords.next().unwrap_or(&line[line.len()..]);
+         let coord = src.strip_prefix(coord, name)?;
+         let (first,last) = src.split_once(coord, "..")?;
+         vol.first[i] = src.parse(first, "an integer")?;
+         vol.end[i] = src.parse::<i64>(last, "an integer")? + 1;
+         if vol.first[i] >= vol.end[i] {
+            return Err(src.error(last, &format!("at least {}", vol.first[i])));
+         }
+      }
+      if let Some(extra) = coords.next() {
+         return Err(src.error(extra, "end of line"));
+      }
+      Ok(Step{ on, vol })
+   }).collect()
+}
+
+// Only cubes within `region` are considered, if given.
+fn solve(steps: &[Step], region: Option<&Volume>) -> u64 {
+   let mut reactor = Reactor::new();
+   let count = steps.len();
+   for (i,mut step) in steps.iter().cloned().enumerate() {
+      info!("step {}/{}...", i, count);
+      if let Some(region) = region {
+         step.vol = match intersect3(&step.vol, region) {
+            Some(vol) => vol,
+            None => continue,
+         };
+      }
+      reactor.step(&step);
+   }
+   reactor.on_count()
+}
+
+// The cubes from `first` up to (but not including) `end`.
+#[derive(PartialEq,Eq,Hash,Clone,Copy)]
+pub struct Volume {
+   first: Vector<3>,
+   end: Vector<3>,
+}
+impl fmt::Debug for Volume {
+   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      write!(f, "{}..{}", self.first, self.end)
+   }
+}
+
+fn volume(vol: &Volume) -> u64 {
+   (vol.end - vol.first).data.iter().map(|&len| len as u64).product()
+}
+
+fn intersect3(a: &Volume, b: &Volume) -> Option<Volume> {
+   let c = Volume{
+      first: a.first.zip_map(b.first, cmp::max),
+      end: a.end.zip_map(b.end, cmp::min),
+   };
+   if (0..3).any(|i| c.first[i] >= c.end[i]) {
+      return None;
+   }
+   //println!("intersect3({:?}, {:?}) -> {:?}", a, b, c);
+   Some(c)
+}
+
+fn sub(a: Volume, b: &Volume) -> Vec<Volume> {
+   //println!("sub({:?}, {:?})", a, b);
+   if let Some(c) = intersect3(&a, b) {
+      if a == c { // oops all gone
+         Vec::new()
+      } else { // At least we get to keep part of it
+         let mut sub_vols = vec![c];
+         let mut ranges = Vec::with_capacity(3);
+         for i in 0..3 {
+            ranges.clear();
+            ranges.push((c.first[i], c.end[i]));
+
+            let before = (a.first[i], c.first[i]);
+            if before.0 != before.1 {
+               ranges.push(before);
+            }
+            let after = (c.end[i], a.end[i]);
+            if after.0 != after.1 {
+               ranges.push(after);
+            }
+            if ranges.len() > 1 {
+               let mut next_sub_vols = Vec::with_capacity(sub_vols.len() * ranges.len());
+               for r in ranges.iter() {
+                  for sub_vol in sub_vols.iter() {
+                     let mut next = *sub_vol;
+                     next.first[i] = r.0;
+                     next.end[i] = r.1;
+                     next_sub_vols.push(next);
+                  }
+               }
+               sub_vols = next_sub_vols;
+            }
+         }
+         sub_vols.retain(|part_of_a| {
+            if let Some(d) = intersect3(part_of_a, b) {
+               assert_eq!(*part_of_a, d);
+               false
+            } else {
+               true
+            }
+         });
+         sub_vols
+      }
+   } else { // no intersection
+      vec![a]
+   }
+}
+/*
+// -> (maybe_a, new)
+fn or(a: Volume, b: Volume) -> (Option<Volume>, Vec<Volume>) {
+   if let Some(c) = intersect3(&a, &b) {
+      if c == a {
+         println!("or: c == a");
+         (None, vec![b])
+      } else if c == *b {
+         println!("or: c == b");
+         (Some(a), vec![])
+      } else {
+         println!("or: a + b-a");
+         // A|B is A + B-A
+         let b_sub_a = sub(b, &a);
+         (Some(a), b_sub_a)
+      }
+   } else {
+      println!("or: disjoint");
+      (Some(a), vec![b])
+   }
+}
+*/
+struct Reactor {
+   on_volumes: Vec<Volume>,
+}
+impl Reactor {
+   fn new() -> Reactor {
+      Reactor{on_volumes: Vec::new()}
+   }
+   fn step(&mut self, step: &Step) {
+      //println!("\nReactor.step {:?}", step);
+      let incoming_vol = &step.vol;
+      let mut next_on_vols: Vec<Volume> = Vec::with_capacity(2*self.on_volumes.len());
+      if step.on {
+         // Two pass
+         // 1. Remove any existing that incoming will subsume
+         // 2. Repeatedly subtract existing from incoming
+         next_on_vols = self.on_volumes.iter().filter(|&v| {
+            if let Some(c) = intersect3(v, incoming_vol) {
+               c != *v
+            } else {
+               true
+            }
+         }).cloned().collect();
+         let mut incoming_parts = vec![*incoming_vol];
+         for existing in next_on_vols.iter() {
+            let mut new_incoming: Vec<Volume> = Vec::with_capacity(incoming_parts.len());
+            for incoming_part in incoming_parts {
+               let inc_sub_exist = sub(incoming_part, existing);
+               new_incoming.extend(inc_sub_exist);
+            }
+            incoming_parts = new_incoming;
+         }
+         next_on_vols.extend(incoming_parts);
+      } else {
+         for existing in self.on_volumes.iter().cloned() {
+            let left_overs = sub(existing, incoming_vol);
+            next_on_vols.extend(left_overs);
+         }
+      }
+      //self.on_volumes.iter().enumerate().for_each(|(i,v)| {
+      //   println!("  before vol {}: {:?}", i, v);
+      //});
+      self.on_volumes = next_on_vols;
+
+      //self.on_volumes.iter().enumerate().for_each(|(i,v)| {
+      //   println!("  after vol {}: {:?}", i, v);
+      //});
+      if true {
+         let mut unique = HashSet::new();
+         for (i,v) in self.on_volumes.iter().enumerate() {
+            let did_insert = unique.insert(v);
+            assert!(did_insert, "dupe: #{}: {:?}", i, v);
+         }
+      }
+   }
+   fn on_count(&self) -> u64 {
+      self.on_volumes.iter().map(volume).sum()
+   }
+}
+
+// -
+
+#[test]
+fn test_example() {
+   let input = "\
+on x=10..12,y=10..12,z=10..12
+";
+   assert_eq!(Day22::solve2(input), 27);
+
+   let input = fixture!("example-small.txt");
+   assert_eq!(Day22::solve2(input), 39);
+
+   let input = fixture!("example-init.txt");
+   assert_eq!(Day22::solve1(input), 590784);
+   assert_eq!(Day22::solve2(input), 590784);
+
+   let input = fixture!("example-init-far.txt");
+   assert_eq!(Day22::solve1(input), 590784);
+
+   let input = fixture!("example-reboot.txt");
+   assert_eq!(Day22::solve1(input), 474140);
+   assert_eq!(Day22::solve2(input), 2758514936282235);
+   assert!(Day22::load("").is_err());
+}
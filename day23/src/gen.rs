@@ -0,0 +1,131 @@
+// Random starting burrows.
+
+use aoc_common::Rng;
+
+// -
+
+// A folded (two-deep) burrow, with up to `size` amphipods out of place.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+   let mut rooms = [*b"AA", *b"BB", *b"CC", *b"DD"];
+   let mut slots: Vec<(usize,usize)> = (0..4)
+      .flat_map(|r| (0..2).map(move |d| (r, d))).collect();
+   rng.shuffle(&mut slots);
+   slots.truncate(size.min(8));
+   let mut whos: Vec<u8> = slots.iter().map(|&(r, d)| rooms[r][d]).collect();
+   rng.shuffle(&mut whos);
+   for (&(r, d), who) in slots.iter().zip(whos) {
+      rooms[r][d] = who;
+   }
+
+   let row = |d: usize| -> String {
+      rooms.iter().map(|room| room[d] as char)
+         .map(|c| c.to_string()).collect::<Vec<_>>().join("#")
+   };
+   format!("\
+#############
+#...........#
+###{}###
+  #{}#
+  #########
+", row(0), row(1))
+}
+
+// -
+
+// The reference: a plain Dijkstra over (hallway, rooms), written from the
+// puzzle rules alone.
+#[cfg(test)]
+fn reference(input: &str) -> Option<u64> {
+   use std::cmp::Reverse;
+   use std::collections::{BinaryHeap, HashMap};
+
+   // 0 is empty, 1-4 are A-D.
+   type State = ([u8; 11], [[u8; 2]; 4]);
+   let lines: Vec<&[u8]> = input.lines().map(|l| l.as_bytes()).collect();
+   let mut rooms = [[0u8; 2]; 4];
+   for (r, room) in rooms.iter_mut().enumerate() {
+      for (d, who) in room.iter_mut().enumerate() {
+         *who = lines[2 + d][3 + 2*r] - b'A' + 1;
+      }
+   }
+   let start: State = ([0; 11], rooms);
+   let door = |r: usize| 2 + 2*r;
+   let energy = |who: u8| 10u64.pow(who as u32 - 1);
+   // Hallway squares strictly between `a` and `b`, and `b` itself.
+   let clear = |hall: &[u8; 11], a: usize, b: usize| {
+      let (lo, hi) = if a < b { (a + 1, b) } else { (b, a - 1) };
+      hall[lo..=hi].iter().all(|&h| h == 0)
+   };
+
+   let mut best: HashMap<State, u64> = HashMap::new();
+   let mut heap = BinaryHeap::new();
+   best.insert(start, 0);
+   heap.push(Reverse((0, start)));
+   while let Some(Reverse((cost, (hall, rooms)))) = heap.pop() {
+      if best[&(hall, rooms)] < cost {
+         continue;
+      }
+      if rooms.iter().enumerate()
+            .all(|(r, room)| room.iter().all(|&w| w as usize == r + 1)) {
+         return Some(cost);
+      }
+      let mut next = Vec::new();
+      // Out of a room, into the hallway (but never in front of a door).
+      for r in 0..4 {
+         let settled = rooms[r].iter().all(|&w| w == 0 || w as usize == r + 1);
+         let d = match rooms[r].iter().position(|&w| w != 0) {
+            Some(d) if !settled => d,
+            _ => continue,
+         };
+         let who = rooms[r][d];
+         for h in [0, 1, 3, 5, 7, 9, 10] {
+            if clear(&hall, door(r), h) {
+               let (mut hall, mut rooms) = (hall, rooms);
+               hall[h] = who;
+               rooms[r][d] = 0;
+               let steps = (d + 1 + h.abs_diff(door(r))) as u64;
+               next.push((cost + steps * energy(who), (hall, rooms)));
+            }
+         }
+      }
+      // Out of the hallway, into its own room once that's only its kind.
+      for h in 0..11 {
+         let who = hall[h];
+         if who == 0 {
+            continue;
+         }
+         let r = who as usize - 1;
+         if !rooms[r].iter().all(|&w| w == 0 || w == who)
+               || !clear(&hall, h, door(r)) {
+            continue;
+         }
+         let d = rooms[r].iter().rposition(|&w| w == 0).unwrap();
+         let (mut hall, mut rooms) = (hall, rooms);
+         hall[h] = 0;
+         rooms[r][d] = who;
+         let steps = (d + 1 + h.abs_diff(door(r))) as u64;
+         next.push((cost + steps * energy(who), (hall, rooms)));
+      }
+      for (cost, state) in next {
+         if best.get(&state).is_none_or(|&b| cost < b) {
+            best.insert(state, cost);
+            heap.push(Reverse((cost, state)));
+         }
+      }
+   }
+   None
+}
+
+#[test]
+fn test_gen() {
+   use aoc_common::Solution;
+   use crate::Day23;
+
+   assert_eq!(reference(aoc_common::fixture!("example.txt")), Some(12521));
+   for seed in 0..10 {
+      let mut rng = Rng::new(seed);
+      let input = input(&mut rng, seed as usize % 9);
+      let expected = reference(&input).unwrap();
+      assert_eq!(Day23::solve1(&input), expected as i64, "{}", input);
+   }
+}
@@ -1,30 +1,42 @@
-use std::fs::File;
-use std::io::Read;
-use std::path::Path;
 use std::cmp;
+use std::fmt;
 use std::str;
 use std::collections::HashMap;
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
-use std::collections::BinaryHeap;
-use std::{thread, time};
+use log::{debug, log, trace, Level};
+use aoc_common::{Result, Solution, Source};
+#[cfg(test)]
+use aoc_common::fixture;
 
+pub mod gen;
 
-const MARKED_MAP: &str = "\
-#############
-#12.3.4.5.67#
-###A#B#C#D###
-  #a#b#c#d#
-  #########";
-
-fn all_coords() -> Vec<Coord> {
-   let coord_by_char: HashMap<char,Coord> = rough_parse(MARKED_MAP)
-      .into_iter().map(|(coord,c)| {
-         (c, coord)
-      }).collect();
-   coord_by_char.into_iter().map(|(c,coord)| coord).collect()
+pub struct Day23;
+
+impl Solution for Day23 {
+   // The starting positions, and how deep the rooms go.
+   type Input = (PositionState, i64);
+   type Answer1 = i64;
+   type Answer2 = i64;
+   const DAY: u32 = 23;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse(input)
+   }
+   // "What is the least energy required to organize the amphipods?"
+   fn part1((initial_pstate, last_y): &Self::Input) -> i64 {
+      solve(initial_pstate, *last_y)
+   }
+   // "Using the initial configuration from the full diagram, what is the
+   //  least energy required to organize the amphipods?"
+   fn part2(folded: &Self::Input) -> i64 {
+      let (initial_pstate, last_y) = unfold(folded);
+      solve(&initial_pstate, last_y)
+   }
 }
 
+// -
+
 #[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
 enum Amphipod {
    A = 1,
@@ -58,7 +70,7 @@ const BLANK_MAP: &str = "\
 0123456789012";
 
 #[derive(Clone,PartialEq,Eq,Hash,PartialOrd,Ord)]
-struct PositionState {
+pub struct PositionState {
    occupant_by_node: BTreeMap<Coord, Amphipod>,
 }
 
@@ -112,13 +124,14 @@ impl PositionState {
                9 => Amphipod::D,
                _ => panic!("impossible coord: {:?}", coord),
             };
-            cost += (room_is_for as i64);
+            cost += room_is_for as i64 ;
          }
          cost
       }).sum()
    }
-
-   fn to_string(&self) -> String {
+}
+impl fmt::Display for PositionState {
+   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
       let mut map = BLANK_MAP.as_bytes().to_vec();
       map.split_mut(|b| *b == b'\n').enumerate().for_each(|(y,line)| {
          line.iter_mut().enumerate().for_each(|(x,c)| {
@@ -133,42 +146,96 @@ impl PositionState {
             }
          });
       });
-      String::from(str::from_utf8(&map).unwrap())
+      write!(f, "{}", str::from_utf8(&map).unwrap())
    }
 }
 
-fn rough_parse(input: &str) -> Vec<(Coord,char)> {
+// The burrow as drawn, where each '.' is a space that may hold an amphipod.
+const TOP: &str = "#############";
+const HALLWAY: &str = "#...........#";
+const FIRST_ROOMS: &str = "###.#.#.#.###";
+const ROOMS: &str = "  #.#.#.#.#";
+const BOTTOM: &str = "  #########";
+
+// Row `y` of the burrow, matched against `template`.
+fn parse_row<'a>(src: &Source, line: &'a str, template: &str, y: i64)
+                 -> Result<Vec<(Coord,Amphipod,&'a str)>> {
    let mut ret = Vec::new();
-   input.trim().lines().enumerate().for_each(|(y,line)| {
-      line.chars().enumerate().for_each(|(x,c)| {
-         match c {
-            '.' | '#' | ' ' => (),
-            _ => {ret.push(((x as i64,y as i64),c)); },
+   let mut chars = line.char_indices();
+   for (x,t) in template.chars().enumerate() {
+      let (i,c) = match chars.next() {
+         Some(next) => next,
+         None => return Err(src.error(&line[line.len()..],
+                                      &format!("{:?}", t))),
+      };
+      let at = &line[i..i+c.len_utf8()];
+      if t != '.' {
+         if c != t {
+            return Err(src.error(at, &format!("{:?}", t)));
          }
-      });
-   });
-   ret
-}
-
-fn parse(input: &str) -> (PositionState, i64) {
-   let rough = rough_parse(input);
-   let mut state = PositionState::new();
-   let mut last_y = 0;
-   for (coord,c) in rough.iter() {
-      let cost = match c {
+         continue;
+      }
+      let who = match c {
+         '.' => continue,
          'A' => Amphipod::A,
          'B' => Amphipod::B,
          'C' => Amphipod::C,
          'D' => Amphipod::D,
-         _ => {
-            panic!("{}{}@{:?}", input, c, coord)
-         },
+         _ => return Err(src.error(at, "an amphipod or open space")),
       };
-      let prev = state.occupant_by_node.insert(*coord, cost);
-      assert!(prev == None);
-      last_y = cmp::max(last_y, coord.1);
+      ret.push(((x as i64,y),who,at));
    }
-   (state, last_y)
+   if let Some((i,_)) = chars.next() {
+      return Err(src.error(&line[i..], "end of line"));
+   }
+   Ok(ret)
+}
+
+// A hallway over rooms 2 or 4 deep, with one amphipod per room space.
+fn parse(input: &str) -> Result<(PositionState, i64)> {
+   let src = Source::new(input);
+   let mut lines = input.trim().lines().map(str::trim_end);
+   let mut next = || lines.next().unwrap_or_else(|| src.end());
+   let mut found = Vec::new();
+   parse_row(&src, next(), TOP, 0)?;
+   found.extend(parse_row(&src, next(), HALLWAY, 1)?);
+   found.extend(parse_row(&src, next(), FIRST_ROOMS, 2)?);
+   let mut last_y = 2;
+   loop {
+      let line = next();
+      if line == BOTTOM {
+         if last_y == 3 || last_y == 5 {
+            break;
+         }
+         return Err(src.error(line, "2 or 4 rows of rooms"));
+      }
+      if last_y == 5 {
+         // Not the bottom, so this says why.
+         parse_row(&src, line, BOTTOM, 6)?;
+      }
+      last_y += 1;
+      found.extend(parse_row(&src, line, ROOMS, last_y)?);
+   }
+   if let Some(extra) = lines.next() {
+      return Err(src.error(extra, "end of input"));
+   }
+
+   let per_type = (last_y - 1) as usize;
+   let mut state = PositionState::new();
+   for who in [Amphipod::A, Amphipod::B, Amphipod::C, Amphipod::D] {
+      let these: Vec<_> = found.iter().filter(|f| f.1 == who).collect();
+      let expected = format!("{} {:?} amphipods", per_type, who);
+      if let Some(extra) = these.get(per_type) {
+         return Err(src.error(extra.2, &format!("only {}", expected)));
+      }
+      if these.len() < per_type {
+         return Err(src.error(src.end(), &expected));
+      }
+      for (coord,_,_) in these {
+         state.occupant_by_node.insert(*coord, who);
+      }
+   }
+   Ok((state, last_y))
 }
 
 /*
@@ -179,14 +246,15 @@ const GOAL_STATE: &str = "\
   #A#B#C#D#
   #########
 ";*/
+// `parse` only allows rooms 2 or 4 deep.
 fn make_goal_state(last_y: i64) -> PositionState {
    assert!(last_y == 3 || last_y == 5);
    let mut state = PositionState::new();
    for to_y in 2..=last_y {
-      state.occupant_by_node.insert((3 as i64, to_y as i64), Amphipod::A);
-      state.occupant_by_node.insert((5 as i64, to_y as i64), Amphipod::B);
-      state.occupant_by_node.insert((7 as i64, to_y as i64), Amphipod::C);
-      state.occupant_by_node.insert((9 as i64, to_y as i64), Amphipod::D);
+      state.occupant_by_node.insert((3_i64, to_y), Amphipod::A);
+      state.occupant_by_node.insert((5_i64, to_y), Amphipod::B);
+      state.occupant_by_node.insert((7_i64, to_y), Amphipod::C);
+      state.occupant_by_node.insert((9_i64, to_y), Amphipod::D);
    }
    state
 }
@@ -227,7 +295,7 @@ impl<V,P> PriorityMap<V,P>
       // I'm so mad. `pop_first` is nightly only, really??
       // Is it supposed to be actually impossible to make a fucking pqueue?
       // No, no-replace heaps don't count.
-      if let Some(tup) = self.ordered_set.iter().cloned().next() {
+      if let Some(tup) = self.ordered_set.iter().next().cloned() {
          self.ordered_set.remove(&tup);
          let (prio,val) = tup;
          self.priority_by_val.remove(&val).unwrap();
@@ -251,9 +319,7 @@ fn gather_valid_moves(from: &Coord, state: &PositionState, last_y: i64) -> Vec<(
 
    // -
 
-   if SPEW {
-      println!("gather_valid_moves({:?})\n{}", from, state.to_string());
-   }
+   trace!("gather_valid_moves({:?})\n{}", from, state);
 
    // Ok, here are our possible states:
    // If we're in the destination room already:
@@ -271,8 +337,8 @@ fn gather_valid_moves(from: &Coord, state: &PositionState, last_y: i64) -> Vec<(
 
    let in_hallway = from.1 == 1;
    let in_destination = from.0 == destination_x;
-   if SPEW { println!("in_hallway: {}", in_hallway); }
-   if SPEW { println!("in_destination: {}", in_destination); }
+   trace!("in_hallway: {}", in_hallway);
+   trace!("in_destination: {}", in_destination);
 
    let is_stranger_in_destination = |at_or_below_y| {
       for to_y in at_or_below_y..=last_y {
@@ -297,24 +363,24 @@ fn gather_valid_moves(from: &Coord, state: &PositionState, last_y: i64) -> Vec<(
             let at = state.occupant_by_node.get(&to);
             assert_eq!(at, Some(mover));
          }
-         if SPEW { println!("room complete"); }
+         trace!("room complete");
          return vec![];
       }
    } else if in_hallway {
       if is_stranger_in_destination(2) {
          // Can't move there!
-         if SPEW { println!("is_stranger_in_destination"); }
+         trace!("is_stranger_in_destination");
          return vec![];
       } else {
          move_to_destination = true;
       }
    } else {
       // Can we leave?
-      let mut front_to_us = (2..=(from.1-1));
+      let front_to_us = 2..=(from.1-1) ;
       for to_y in front_to_us.rev() {
          let to: Coord = (from.0, to_y);
          if state.occupant_by_node.contains_key(&to) {
-            if SPEW { println!("trapped!"); }
+            trace!("trapped!");
             return vec![];
          }
       }
@@ -325,11 +391,11 @@ fn gather_valid_moves(from: &Coord, state: &PositionState, last_y: i64) -> Vec<(
 
    // -
 
-   if SPEW { println!("move_to_hallway: {}", move_to_hallway); }
-   if SPEW { println!("move_to_destination: {}", move_to_destination); }
+   trace!("move_to_hallway: {}", move_to_hallway);
+   trace!("move_to_destination: {}", move_to_destination);
 
    if move_to_hallway {
-      try_coords = HALLWAY_X_LIST.iter().map(|to_x| (*to_x, 1 as i64)).collect();
+      try_coords = HALLWAY_X_LIST.iter().map(|to_x| (*to_x, 1_i64)).collect();
    }
    if move_to_destination {
       let mut avail: Option<Coord> = None;
@@ -344,7 +410,7 @@ fn gather_valid_moves(from: &Coord, state: &PositionState, last_y: i64) -> Vec<(
       if let Some(to) = avail {
          try_coords.push(to);
       } else {
-         if SPEW { println!("no dest slots avail"); }
+         trace!("no dest slots avail");
       }
    }
 
@@ -355,8 +421,7 @@ fn gather_valid_moves(from: &Coord, state: &PositionState, last_y: i64) -> Vec<(
 
    let mut left_end = 0;
    let mut right_end = 12;
-   for hallway_person in state.occupant_by_node.iter()
-            .map(|(coord,_)| coord)
+   for hallway_person in state.occupant_by_node.keys()
             .filter(|coord| *coord != from && coord.1 == 1) {
       if hallway_person.0 < from.0 {// left
          left_end = cmp::max(left_end, hallway_person.0);
@@ -364,23 +429,19 @@ fn gather_valid_moves(from: &Coord, state: &PositionState, last_y: i64) -> Vec<(
          right_end = cmp::min(right_end, hallway_person.0);
       }
    }
-   if SPEW {
-      println!("  ends: {} {}", left_end, right_end);
-      println!("  try_coords: {:?}", try_coords);
-   }
+   trace!("  ends: {} {}", left_end, right_end);
+   trace!("  try_coords: {:?}", try_coords);
 
    let next_coords: Vec<Coord> = try_coords.into_iter().filter(|to| {
       if to.0 <= left_end || right_end <= to.0 {
          return false;
       }
-      if state.occupant_by_node.contains_key(&to) {
+      if state.occupant_by_node.contains_key(to) {
          return false;
       }
       true
    }).collect();
-   if SPEW {
-      println!("  next_coords: {:?}", next_coords);
-   }
+   trace!("  next_coords: {:?}", next_coords);
 
    let ret: Vec<(PositionState,i64)> = next_coords.into_iter().map(|to| {
       state.mov(from, &to)
@@ -391,20 +452,11 @@ fn gather_valid_moves(from: &Coord, state: &PositionState, last_y: i64) -> Vec<(
    ret
 }
 
-const SPEW: bool = false;
-
-// "What is the least energy required to organize the amphipods?"
-fn solve(input: &str) -> i64 {
-
-   let (initial_pstate, last_y) = parse(input);
+fn solve(initial_pstate: &PositionState, last_y: i64) -> i64 {
    let goal_state = make_goal_state(last_y);
 
-   for _ in 0..10 {
-      println!("");
-   }
-   println!("input:\n{}", input);
-   println!("initial_state:\n{}", initial_pstate.to_string());
-   println!("goal_state:\n{}", goal_state.to_string());
+   debug!("initial_state:\n{}", initial_pstate);
+   debug!("goal_state:\n{}", goal_state);
 
    let mut cost_by_state: HashMap<PositionState, i64> = HashMap::new();
    let mut fringe: PriorityMap<PositionState, i64> = PriorityMap::new();
@@ -417,11 +469,9 @@ fn solve(input: &str) -> i64 {
       }
 
       let i = ii.next().unwrap();
-      if SPEW || i % 1000 <= 0 {
-         println!("\n[{}] Popping one from among {}:", i, fringe.len());
-         println!("{}", state.to_string());
-         //panic!("stop there");
-      }
+      let level = if i % 1000 == 0 { Level::Info } else { Level::Trace };
+      log!(level, "[{}] Popping one from among {}:\n{}",
+           i, fringe.len(), state);
       if i >= 10 {
          //panic!("stop there");
       }
@@ -436,16 +486,12 @@ fn solve(input: &str) -> i64 {
          for (new_state,additional_cost) in valid_moves {
             let new_cost = cur_cost + additional_cost;
 
-            if SPEW {
-               println!("   new_state:\n{}", new_state.to_string());
-            }
+            trace!("   new_state:\n{}", new_state);
 
             let e = cost_by_state.entry(new_state.clone())
                   .or_insert(new_cost+1); // Always "find" a new min!
 
-            if SPEW {
-               println!("  new_cost {}, current best: {}", new_cost, *e);
-            }
+            trace!("  new_cost {}, current best: {}", new_cost, *e);
             if new_cost < *e {
                *e = new_cost;
                let cost_remaining = new_state.est_cost_remaining();
@@ -460,9 +506,39 @@ fn solve(input: &str) -> i64 {
    *cost_by_state.get(&goal_state).unwrap()
 }
 
+// "Between the first and second lines of text that contain amphipod starting
+//  positions, insert the following lines:
+//    #D#C#B#A#
+//    #D#B#A#C#"
+// A diagram that's already full is left as is.
+fn unfold((state, last_y): &(PositionState, i64)) -> (PositionState, i64) {
+   if *last_y == 5 {
+      return (state.clone(), 5);
+   }
+   let mut unfolded = PositionState::new();
+   for (coord,who) in state.occupant_by_node.iter() {
+      let mut coord = *coord;
+      if coord.1 == 3 {
+         coord.1 = 5;
+      }
+      unfolded.occupant_by_node.insert(coord, *who);
+   }
+   use Amphipod::*;
+   let inserted = [
+      (3, [D, C, B, A]),
+      (4, [D, B, A, C]),
+   ];
+   for (y,row) in inserted.iter() {
+      for (x,who) in [3, 5, 7, 9].iter().zip(row.iter()) {
+         unfolded.occupant_by_node.insert((*x, *y), *who);
+      }
+   }
+   (unfolded, 5)
+}
+
 // -
 
-//#[test]
+#[test]
 fn test_example() {
    let input = "\
 #############
@@ -471,8 +547,8 @@ fn test_example() {
   #A#B#C#D#
   #########
 ";
-   assert_eq!(solve(&input), 0);
-   assert_eq!(parse(&input).0.est_cost_remaining(), 0);
+   assert_eq!(Day23::solve1(input), 0);
+   assert_eq!(parse(input).unwrap().0.est_cost_remaining(), 0);
    // -
 
    let input = "\
@@ -482,9 +558,8 @@ fn test_example() {
   #A#B#C#D#
   #########
 ";
-   assert_eq!(parse(&input).0.est_cost_remaining(), 2);
-   println!("est_cost_remaining ok");
-   assert_eq!(solve(&input), 2);
+   assert_eq!(parse(input).unwrap().0.est_cost_remaining(), 2);
+   assert_eq!(Day23::solve1(input), 2);
 
    let input = "\
 #############
@@ -493,8 +568,8 @@ fn test_example() {
   #A#B#C#D#
   #########
 ";
-   assert_eq!(solve(&input), 20);
-   assert_eq!(parse(&input).0.est_cost_remaining(), 20);
+   assert_eq!(Day23::solve1(input), 20);
+   assert_eq!(parse(input).unwrap().0.est_cost_remaining(), 20);
 
    let input = "\
 #############
@@ -503,8 +578,8 @@ fn test_example() {
   #A#B#C#D#
   #########
 ";
-   assert_eq!(solve(&input), 200);
-   assert_eq!(parse(&input).0.est_cost_remaining(), 200);
+   assert_eq!(Day23::solve1(input), 200);
+   assert_eq!(parse(input).unwrap().0.est_cost_remaining(), 200);
 
    let input = "\
 #############
@@ -513,8 +588,8 @@ fn test_example() {
   #A#B#C#D#
   #########
 ";
-   assert_eq!(solve(&input), 2000);
-   assert_eq!(parse(&input).0.est_cost_remaining(), 2000);
+   assert_eq!(Day23::solve1(input), 2000);
+   assert_eq!(parse(input).unwrap().0.est_cost_remaining(), 2000);
 
    // -
 
@@ -525,7 +600,7 @@ fn test_example() {
   #A#B#C#D#
   #########
 ";
-   //assert_eq!(solve(&input), 8010);
+   assert_eq!(Day23::solve1(input), 8010);
 
    let input = "\
 #############
@@ -534,7 +609,7 @@ fn test_example() {
   #A#B#C#A#
   #########
 ";
-   assert_eq!(solve(&input), 13009);
+   assert_eq!(Day23::solve1(input), 13009);
    //panic!("good!");
    // -
    let input = "\
@@ -544,21 +619,16 @@ fn test_example() {
   #A#B#C#A#
   #########
 ";
-   assert_eq!(solve(&input), 13011);
+   assert_eq!(Day23::solve1(input), 13011);
    //panic!("good!");
    // -
 
-   let input = "\
-#############
-#...........#
-###B#C#B#D###
-  #A#D#C#A#
-  #########
-";
-   //assert_eq!(solve(&input), 12521);
+   let input = fixture!("example.txt");
+   assert_eq!(Day23::solve1(input), 12521);
+   assert_eq!(Day23::solve2(input), 44169);
    //panic!("good!");
 
-   let input = "\
+   let _input = "\
 #############
 #...........#
 ###B#C#B#D###
@@ -762,41 +832,33 @@ fn test_example() {
 
 
 
-   let input = "\
-#############
-#...........#
-###B#C#B#D###
-  #D#C#B#A#
-  #D#B#A#C#
-  #A#D#C#A#
-  #########
-";
-   assert_eq!(solve(&input), 44169);
+   let input = fixture!("example-unfolded.txt");
+   assert_eq!(Day23::solve1(input), 44169);
+   assert_eq!(Day23::solve2(input), 44169);
    //panic!("good!!");
-}
 
-fn main() {
-   test_example();
-   println!("Examples ran clean!");
-   thread::sleep(time::Duration::from_millis(1000));
-
-   let path = Path::new("day23-1/input.txt");
-   let mut file = match File::open(&path) {
-      Err(why) => panic!("File::open({}) -> Err({})", path.display(), why),
-      Ok(file) => file,
-   };
-   let mut input = String::new();
-   file.read_to_string(&mut input).unwrap();
-
-   input = "\
-#############
-#...........#
-###C#A#D#D###
-  #D#C#B#A#
-  #D#B#A#C#
-  #B#A#B#C#
-  #########
-".to_string();
+   // -
 
-   println!("solve_p2(input) -> {}", solve(&input));
+   let err = |input: &str| Day23::load(input).err().unwrap().to_string();
+   let folded = fixture!("example.txt");
+   assert_eq!(err(""), "day23: line 1, column 1: expected '#', \
+                        found end of input");
+   assert_eq!(err(&folded.replace("#...", "#.x.")),
+              "day23: line 2, column 3: expected an amphipod or open space, \
+               found \"x\"");
+   assert_eq!(err(&folded.replace(".#\n", ".\n")),
+              "day23: line 2, column 13: expected '#', found end of line");
+   assert_eq!(err(&folded.replace("  #A#D#C#A#\n", "")),
+              "day23: line 4, column 1: expected 2 or 4 rows of rooms, \
+               found \"  #########\"");
+   assert_eq!(err(&folded.replace("#B#D###", "#B#D####")),
+              "day23: line 3, column 14: expected end of line, found \"#\"");
+   assert_eq!(err(&format!("{}#", folded)),
+              "day23: line 6, column 1: expected end of input, found \"#\"");
+   assert_eq!(err(&folded.replace("###B#C", "###A#C")),
+              "day23: line 4, column 10: expected only 2 A amphipods, \
+               found \"A\"");
+   assert_eq!(err(&folded.replace("###B#C", "###.#C")),
+              "day23: line 6, column 1: expected 2 B amphipods, \
+               found end of input");
 }
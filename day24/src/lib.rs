@@ -1,11 +1,37 @@
-use std::fs::File;
-use std::io::Read;
-use std::path::Path;
 use std::collections::HashMap;
-use std::collections::HashSet;
+use std::fmt;
 use std::collections::BTreeSet;
 use std::collections::BTreeMap;
-use std::{thread, time};
+use aoc_common::{Result, Solution, Source};
+use log::{debug, info};
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+   // The MONAD program.
+   type Input = Vec<AluInst>;
+   type Answer1 = u64;
+   type Answer2 = u64;
+   const DAY: u32 = 24;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse_monad(input)
+   }
+   // "What is the largest model number accepted by MONAD?"
+   fn part1(prog: &Self::Input) -> u64 {
+      let highest = valid_model_numbers(prog).into_iter().max().unwrap();
+      highest.parse().unwrap()
+   }
+   // "What is the smallest model number accepted by MONAD?"
+   fn part2(prog: &Self::Input) -> u64 {
+      let lowest = valid_model_numbers(prog).into_iter().min().unwrap();
+      lowest.parse().unwrap()
+   }
+}
+
+// -
 
 #[derive(PartialEq,Eq,Clone,Debug,Hash,PartialOrd,Ord)]
 struct AluState {
@@ -30,11 +56,13 @@ impl AluState {
       *reg = val;
       self
    }
-   fn to_string(&self) -> String {
+}
+impl fmt::Display for AluState {
+   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
       let regs: Vec<String> = self.register_by_name.iter().map(|(reg,val)| {
          format!("{}: {}", reg, val)
       }).collect();
-      regs.join("\n")
+      write!(f, "{}", regs.join("\n"))
    }
 }
 #[derive(Debug,Clone,PartialEq,Eq,Hash)]
@@ -42,37 +70,48 @@ enum AluInstArg {
    Reg(char),
    Lit(i64),
 }
-impl AluInstArg {
-   fn to_string(&self) -> String {
+impl fmt::Display for AluInstArg {
+   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
       match *self {
-         Self::Reg(reg) => reg.to_string(),
-         Self::Lit(lit) => lit.to_string(),
+         Self::Reg(reg) => write!(f, "{}", reg),
+         Self::Lit(lit) => write!(f, "{}", lit),
       }
    }
 }
 
 
 #[derive(Debug,Clone,PartialEq,Eq,Hash)]
-struct AluInst {
+pub struct AluInst {
    name: String,
    args: Vec<AluInstArg>,
 }
 impl AluInst {
-   fn parse(line: &str) -> AluInst {
-      let (name, args) = line.split_once(' ').unwrap();
-      let (reg, args) = line.split_once(' ').unwrap();
-      AluInst {
-         name: name.to_string(),
-         args: args.split(' ').map(|arg| {
-            if let Ok(arg) = arg.parse::<i64>() {
-               AluInstArg::Lit(arg)
-            } else if arg.len() == 1 {
-               AluInstArg::Reg(arg.chars().next().unwrap())
-            } else {
-               panic!("{}", arg);
-            }
-         }).collect(),
+   fn parse(src: &Source, line: &str) -> Result<AluInst> {
+      let (name, args_s) = src.split_once(line, " ")?;
+      let arg_count = match name {
+         "inp" => 1,
+         "add" | "mul" | "div" | "mod" | "eql" => 2,
+         _ => return Err(src.error(name, "an instruction")),
+      };
+      let args = args_s.split(' ').map(|arg| {
+         if let Ok(arg) = arg.parse::<i64>() {
+            Ok(AluInstArg::Lit(arg))
+         } else if arg.len() == 1 && "wxyz".contains(arg) {
+            Ok(AluInstArg::Reg(arg.chars().next().unwrap()))
+         } else {
+            Err(src.error(arg, "a register or integer"))
+         }
+      }).collect::<Result<Vec<_>>>()?;
+      if args.len() != arg_count {
+         return Err(src.error(args_s, &format!("{} arguments", arg_count)));
+      }
+      if let AluInstArg::Lit(_) = args[0] {
+         return Err(src.error(args_s, "a register to write to"));
       }
+      Ok(AluInst {
+         name: name.to_string(),
+         args,
+      })
    }
 
    fn run<'a>(&self, mut state: AluState, mut input: &'a str) -> (AluState, &'a str) {
@@ -107,28 +146,55 @@ impl AluInst {
       *left = op_fn(*left, right);
       (state, input)
    }
-
-   fn to_string(&self) -> String {
+}
+impl fmt::Display for AluInst {
+   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
       let args: Vec<String> = self.args.iter()
             .map(|x| x.to_string()).collect();
-      format!("{} {}", self.name, args.join(" "))
+      write!(f, "{} {}", self.name, args.join(" "))
    }
 }
 
-fn parse(input: &str) -> Vec<AluInst> {
+fn parse(input: &str) -> Result<Vec<AluInst>> {
+   let src = Source::new(input);
    input.trim().lines().map(|line| {
-      AluInst::parse(line)
+      AluInst::parse(&src, line)
    }).collect()
 }
 
+// MONAD: 14 blocks, each reading the next digit into w.
+fn parse_monad(input: &str) -> Result<Vec<AluInst>> {
+   let src = Source::new(input);
+   let prog = parse(input)?;
+   let mut blocks = 0;
+   for (line, inst) in input.trim().lines().zip(prog.iter()) {
+      if inst.name != "inp" && blocks > 0 {
+         continue;
+      }
+      if line != "inp w" {
+         return Err(src.error(line, "\"inp w\""));
+      }
+      if blocks == 14 {
+         return Err(src.error(line, "only 14 \"inp w\" blocks"));
+      }
+      blocks += 1;
+   }
+   if blocks < 14 {
+      return Err(src.error(src.end(), "14 \"inp w\" blocks"));
+   }
+   Ok(prog)
+}
+
 #[derive(Clone,PartialEq,Eq,Hash)]
 struct AluProg(Vec<AluInst>);
-impl AluProg {
-   fn to_string(&self) -> String {
+impl fmt::Display for AluProg {
+   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
       let lines: Vec<String> = self.0.iter().enumerate()
-         .map(|(i,x)| format!("[{:4}] {}", i, x.to_string())).collect();
-      lines.join("\n")
+         .map(|(i,x)| format!("[{:4}] {}", i, x)).collect();
+      write!(f, "{}", lines.join("\n"))
    }
+}
+impl AluProg {
    fn run<'a>(&self, mut state: AluState, mut input: &'a str)
             -> (AluState, &'a str) {
       for inst in self.0.iter() {
@@ -140,8 +206,9 @@ impl AluProg {
 }
 
 
-fn run(program: &Vec<AluInst>, mut input: &str) -> AluState {
-   println!("input: {}", input);
+#[cfg(test)]
+fn run(program: &[AluInst], mut input: &str) -> AluState {
+   debug!("input: {}", input);
    let mut state = AluState::new();
    for inst in program.iter() {
       let (new_state, new_input) = inst.run(state, input);
@@ -149,7 +216,7 @@ fn run(program: &Vec<AluInst>, mut input: &str) -> AluState {
       input = new_input;
    }
    assert_eq!(input.len(), 0);
-   println!("end state:\n{}\n", state.to_string());
+   debug!("end state:\n{}", state);
    state
 }
 
@@ -165,45 +232,49 @@ fn enumerate_run_states(prog: &AluProg, mut state: AluState) -> Vec<AluState> {
 
 // -
 
-//#[test]
+#[test]
 fn test_example() {
-   let prog = "\
-inp x
-mul x -1
-";
-   println!("\nExample:\n{}", prog.trim());
-   let prog = parse(prog);
+   let prog = fixture!("negate.txt");
+   let prog = parse(prog).unwrap();
    assert_eq!(run(&prog, "0").get('x'), 0);
    assert_eq!(run(&prog, "1").get('x'), -1);
    assert_eq!(run(&prog, "2").get('x'), -2);
 
-   let prog = "\
-inp z
-inp x
-mul z 3
-eql z x
-";
-   println!("\nExample:\n{}", prog.trim());
-   let prog = parse(prog);
+   let prog = fixture!("triple.txt");
+   let prog = parse(prog).unwrap();
    assert_eq!(run(&prog, "00").get('z'), 1);
    assert_eq!(run(&prog, "01").get('z'), 0);
    assert_eq!(run(&prog, "11").get('z'), 0);
    assert_eq!(run(&prog, "13").get('z'), 1);
    assert_eq!(run(&prog, "26").get('z'), 1);
-}
 
-fn read_input_file(path: &str) -> String {
-   let path = Path::new(path);
-   let mut file = match File::open(&path) {
-      Err(why) => panic!("File::open({}) -> Err({})", path.display(), why),
-      Ok(file) => file,
-   };
-   let mut input = String::new();
-   file.read_to_string(&mut input).unwrap();
-   input
+   let e = parse("inp z\nmul z q\n").unwrap_err();
+   assert_eq!(e.to_string(),
+              "line 2, column 7: expected a register or integer, found \"q\"");
+   let e = parse("inp z\nadd z\n").unwrap_err();
+   assert_eq!((e.line, e.col), (2,5));
+   assert_eq!(e.expected, "2 arguments");
+
+   let err = |input: &str| Day24::load(input).err().unwrap().to_string();
+   assert_eq!(err(""), "day24: line 1, column 1: expected 14 \"inp w\" \
+                        blocks, found end of input");
+   assert_eq!(err(fixture!("negate.txt")),
+              "day24: line 1, column 1: expected \"inp w\", found \"inp x\"");
+   let block = "inp w\nadd z w\n";
+   assert_eq!(err(&block.repeat(13)),
+              "day24: line 27, column 1: expected 14 \"inp w\" blocks, \
+               found end of input");
+   assert_eq!(err(&block.repeat(15)),
+              "day24: line 29, column 1: expected only 14 \"inp w\" blocks, \
+               found \"inp w\"");
+   assert_eq!(err(&block.repeat(14).replacen("inp w", "add z 1", 1)),
+              "day24: line 1, column 1: expected \"inp w\", \
+               found \"add z 1\"");
+   assert!(Day24::load(&block.repeat(14)).is_ok());
 }
 
-fn enum_possible_lines(progs: &Vec<AluProg>)
+#[allow(dead_code)]
+fn enum_possible_lines(progs: &[AluProg])
    -> BTreeSet<(usize, String)>
 {
    let mut ret = BTreeSet::new();
@@ -215,6 +286,7 @@ fn enum_possible_lines(progs: &Vec<AluProg>)
    ret
 }
 
+#[allow(dead_code)]
 fn crack(prog: &AluProg, zmod_out: i64) -> Vec<AluState> {
    let mut poss_eff_state = Vec::new();
    for w in 1..=9 {
@@ -229,7 +301,7 @@ fn crack(prog: &AluProg, zmod_out: i64) -> Vec<AluState> {
    // -
 
    let mut in_state_by_zmod_out: HashMap<i64, Vec<AluState>> = HashMap::new();
-   println!("\ncracking:\n{}", prog.to_string());
+   debug!("cracking:\n{}", prog);
    for state in poss_eff_state.iter() {
       let states = enumerate_run_states(prog, state.clone());
       if states[7].get('x') != 0 {
@@ -237,96 +309,37 @@ fn crack(prog: &AluProg, zmod_out: i64) -> Vec<AluState> {
       }
       let end_state = states.last().unwrap();
       let z = end_state.get('z') % 26;
-      let e = in_state_by_zmod_out.entry(z).or_insert(Vec::new());
+      let e = in_state_by_zmod_out.entry(z).or_default();
       e.push(end_state.clone());
    }
    for (i,(zmod,vec)) in in_state_by_zmod_out.iter().enumerate() {
-      println!("zmod[{}]: {} in {} states", i, zmod, vec.len());
+      debug!("zmod[{}]: {} in {} states", i, zmod, vec.len());
    }
    in_state_by_zmod_out.get(&zmod_out).unwrap().clone()
 }
 
-fn main() {
-   test_example();
-   println!("Examples ran clean!");
-   thread::sleep(time::Duration::from_millis(1000));
-
-   let prog = read_input_file("day24-1/input.txt");
-   println!("\nMONAD:\n{}", prog.trim());
-   let prog = parse(&prog);
-   let input = "13579246899999";
-   println!("MONAD({}) -> {:?}", input, run(&prog, &input).get('z') == 0);
+// -
 
-   // Split into subprogs,
-   // skipping inp which we'll assign to w manually.
+// Split into subprogs,
+// skipping inp which we'll assign to w manually.
+fn split_subprogs(prog: &[AluInst]) -> Vec<AluProg> {
    let mut subprogs: Vec<AluProg> = Vec::new();
-   {
-      let mut subprog: AluProg = AluProg(Vec::new());
-      for inst in prog.iter() {
-         if inst.name == "inp" {
-            if subprog.0.len() > 0 {
-               subprogs.push(subprog.clone());
-               subprog.0.clear();
-            }
-         } else {
-            subprog.0.push(inst.clone());
-         }
-      }
-      subprogs.push(subprog);
-   }
-   assert_eq!(subprogs.len(), 14);
-   println!("\nenum_possible_lines:");
-   for (i,line) in enum_possible_lines(&subprogs).iter() {
-      println!("[{:4}] {}", i, line);
-   }
-   let deduped: HashSet<AluProg> = subprogs.iter().cloned().collect();
-   println!("\n{} -> {} after deduping", subprogs.len(), deduped.len());
-   //for (i,sub) in inp_subprogs.iter().enumerate() {
-   //   println!("input[{}]:\n{}", i, sub.to_string("  "));
-   //}
-/*
-   let mut poss_inputs: Vec<String> = vec!["".to_string()];
-   for _ in 0..1 {
-      let mut next = Vec::new();
-      for x in poss_inputs {
-         for i in 1..=9 {
-            let s = x.to_string() + &i.to_string();
-            next.push(s);
+   let mut subprog: AluProg = AluProg(Vec::new());
+   for inst in prog.iter() {
+      if inst.name == "inp" {
+         if !subprog.0.is_empty() {
+            subprogs.push(subprog.clone());
+            subprog.0.clear();
          }
-      }
-      poss_inputs = next;
-   }
-   let mut poss_eff_state = Vec::new();
-   for w in 1..=9 {
-      for zmod26 in 0..26 {
-         let state = AluState::new()
-            .set('w', w)
-            .set('z', zmod26);
-         poss_eff_state.push(state);
-      }
-   }
-
-   let mut inputs_by_output: HashMap<i64, Vec<String>> = HashMap::new();
-
-   let mut all_progs = subprogs.len();
-   for i in 0..all_progs {
-      let mut in_state_by_zmod_out: HashMap<i64, Vec<AluState>> = HashMap::new();
-      let subprog = &subprogs[i];
-      println!("input[{}]:\n{}", i, subprog.to_string());
-      for state in poss_eff_state.iter() {
-         let (state2, _) = subprog.run(state.clone(), "");
-         let zmod = state2.get('z') % 26;
-         let e = in_state_by_zmod_out.entry(zmod).or_insert(Vec::new());
-         e.push(state2);
-      }
-      for (i,(zmod,vec)) in in_state_by_zmod_out.iter().enumerate() {
-         println!("zmod[{}]: {} in {} states", i, zmod, vec.len());
+      } else {
+         subprog.0.push(inst.clone());
       }
    }
+   subprogs.push(subprog);
+   subprogs
+}
 
-*/
-   // -
-   /*
+/*
       A  ,   B  ,  C
  0:   1  ,  13  ,  5
  1:   1  ,  15  , 14
@@ -342,12 +355,15 @@ fn main() {
 11:  26  , -11  ,  9
 12:   1  ,  12  , 11
 13:  26  , -15  ,  5
-   */
+*/
+fn valid_model_numbers(prog: &[AluInst]) -> Vec<String> {
+   let subprogs = split_subprogs(prog);
+   assert_eq!(subprogs.len(), 14);
 
-   let mut states: Vec<(String,AluState)> = Vec::new();
-   states.push((String::new(), AluState::new()));
+   let mut states: Vec<(String,AluState)> =
+      vec![(String::new(), AluState::new())];
    for (i,prog) in subprogs.iter().enumerate() {
-      println!("i={}: {} in play", i, states.len());
+      info!("i={}: {} in play", i, states.len());
       let a = &prog.0[3];
       let b = &prog.0[4];
       let c = &prog.0[14];
@@ -358,15 +374,13 @@ fn main() {
               else { panic!("{:?}", a) };
       let b = if let AluInstArg::Lit(lit) = b.args[1] { lit }
               else { panic!("{:?}", b) };
-      let c = if let AluInstArg::Lit(lit) = c.args[1] { lit }
-              else { panic!("{:?}", c) };
       assert_eq!(a == 1, b >= 10);
       assert_eq!(a == 26, b <= -6);
       let mut new_states = Vec::new();
       for (input, state) in states.iter() {
          let w_list = {
             let ideal_w = state.get('z') % 26 + b;// Wi = z%26 + Bi
-            if 1 <= ideal_w && ideal_w <= 9 {
+            if (1..=9).contains(&ideal_w) {
                //println!("Picking ideal i[{}]: {}", i, ideal_w);
                vec![ideal_w]
             } else if b < 0 {
@@ -386,38 +400,10 @@ fn main() {
       }
       states = new_states;
    }
-   println!("{} states remain!", states.len());
-
-   let highest = states.iter().max().unwrap();
-   println!("highest {}-digit: {}\n{}",
-   highest.0.len(), highest.0, highest.1.to_string());
-
-   let lowest = states.iter().map(|(input,_)| input).min().unwrap();
-   println!("lowest {}-digit: {}", lowest.len(), lowest);
-
-   for (input,state) in states.iter() {
-      assert_eq!(state.get('z'), 0, "{}", state.to_string());
-   }
-
+   info!("{} states remain!", states.len());
 
-   //crack(&subprogs[subprogs.len()-1], 0);
-
-   /*
-   for poss_input in poss_inputs.into_iter() {
-      let mut state = AluState::new();
-      let mut input: &str = &poss_input;
-      for i in 0..2 {
-         let subprog = &inp_subprogs[i];
-         println!("input[{}]:\n{}", i, subprog.to_string("  "));
-         let res = subprog.run(state, input);
-         state = res.0; input = res.1;
-      }
-      assert_eq!(input.len(), 0);
-      let zmod26 = state.get('z') % 26;
-      let e = inputs_by_output.entry(zmod26).or_insert(Vec::new());
-      e.push(poss_input);
+   for (_,state) in states.iter() {
+      assert_eq!(state.get('z'), 0, "{}", state);
    }
-   println!("inputs_by_output: {}: {:?}", inputs_by_output.len(),
-         inputs_by_output);
-   */
+   states.into_iter().map(|(input,_)| input).collect()
 }
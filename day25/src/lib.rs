@@ -0,0 +1,105 @@
+use aoc_common::{Grid, Point, Result, Solution, Vector};
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+   type Input = State;
+   type Answer1 = usize;
+   type Answer2 = usize;
+   const DAY: u32 = 25;
+   const HAS_PART2: bool = false;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse(input)
+   }
+   fn part1(state: &Self::Input) -> usize {
+      part1(state)
+   }
+   fn part2(_: &Self::Input) -> usize {
+      unreachable!("Day 25 only has the one puzzle.")
+   }
+}
+
+// -
+
+#[derive(Clone,Copy,PartialEq)]
+enum Herd {
+   East = 0,
+   South = 1,
+}
+
+#[derive(Clone)]
+pub struct State {
+   herd_by_coord: Grid<Option<Herd>>,
+}
+impl State {
+   fn step(&self) -> (State, i64) {
+      let (next_e, moves_e) = self.step_for(Herd::East);
+      let (next_s, moves_s) = next_e.step_for(Herd::South);
+      (next_s, moves_e + moves_s)
+   }
+   fn step_for(&self, move_herd: Herd) -> (State, i64) {
+      let mut next = self.clone();
+      let offset = match move_herd {
+         Herd::East => Vector::new([1, 0]),
+         Herd::South => Vector::new([0, 1]),
+      };
+      let mut moves = 0;
+      for (pos, herd) in self.herd_by_coord.iter() {
+         if *herd != Some(move_herd) { continue; }
+         if let Some(next_pos) = self.try_mov(&pos, offset) {
+            *next.herd_by_coord.at_mut(&pos) = None;
+            *next.herd_by_coord.at_mut(&next_pos) = *herd;
+            moves += 1;
+         } // Else don't move
+      }
+      (next, moves)
+   }
+   fn try_mov(&self, at: &Point, offset: Vector<2>) -> Option<Point> {
+      // "Sea cucumbers that move off the right edge of the map appear on
+      //  the left edge, and sea cucumbers that move off the bottom edge of
+      //  the map appear on the top edge."
+      let at = self.herd_by_coord.wrap(&(*at + offset));
+      if self.herd_by_coord.at(&at).is_none() {
+         Some(at)
+      } else {
+         None
+      }
+   }
+}
+
+fn parse(input: &str) -> Result<State> {
+   let herd_by_coord = Grid::from_char_map(input, "\">\", \"v\" or \".\"", |c| {
+      match c {
+         '>' => Some(Some(Herd::East)),
+         'v' => Some(Some(Herd::South)),
+         '.' => Some(None),
+         _ => None,
+      }
+   })?;
+   Ok(State { herd_by_coord })
+}
+
+// "What is the first step on which no sea cucumbers move?"
+fn part1(state: &State) -> usize {
+   let mut state = state.clone();
+   for i in 1.. {
+      let (next, moves) = state.step();
+      if moves == 0 {
+         return i;
+      }
+      state = next;
+   }
+   panic!("unreachable");
+}
+
+// -
+
+#[test]
+fn test_example() {
+   let input = fixture!("example.txt");
+   assert_eq!(Day25::solve1(input), 58);
+   assert!(Day25::load("").is_err());
+}
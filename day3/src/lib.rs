@@ -0,0 +1,133 @@
+use aoc_common::{Result, Solution, Source};
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+   type Input = Vec<Vec<char>>;
+   type Answer1 = u64;
+   type Answer2 = u64;
+   const DAY: u32 = 3;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse_inputs(input)
+   }
+   fn part1(inputs: &Self::Input) -> u64 {
+      part1(inputs)
+   }
+   fn part2(inputs: &Self::Input) -> u64 {
+      part2(inputs)
+   }
+}
+
+// -
+
+fn parse_inputs(input: &str) -> Result<Vec<Vec<char>>> {
+   let src = Source::new(input);
+   src.not_empty("a binary number")?;
+   let lines: Vec<_> = input.split('\n').filter(|x| !x.is_empty()).collect();
+   let width = lines.first().map(|x| x.len());
+   lines.iter()
+      .map(|x| {
+         if let Some(bad) = x.find(|c| c != '0' && c != '1') {
+            return Err(src.error(&x[bad..], "a binary digit"));
+         }
+         if Some(x.len()) != width {
+            return Err(src.error(&x[x.len()..],
+                                 &format!("{} bits", width.unwrap())));
+         }
+         Ok(x.chars().collect())
+      }).collect()
+}
+
+// "What is the power consumption of the submarine?"
+fn part1(inputs: &[Vec<char>]) -> u64 {
+   let mut one_counts = vec![0; inputs[0].len()];
+
+   let mut input_count = 0;
+   for cur_bits in inputs.iter() {
+      input_count += 1;
+      for (one_count, cur_bit) in one_counts.iter_mut().zip(cur_bits.iter()) {
+         if *cur_bit == '1' {
+            *one_count += 1;
+         }
+      }
+   }
+
+   let more_threshold = input_count / 2;
+   let gamma = one_counts.iter().fold(0u64, |mut gamma, one_count| {
+      gamma <<= 1;
+      if one_count > &more_threshold {
+         gamma |= 1;
+      }
+      gamma
+   });
+   let epsilon = !gamma & ((1 << one_counts.len())-1);
+
+   gamma * epsilon
+}
+
+// "What is the life support rating of the submarine?"
+fn part2(inputs: &[Vec<char>]) -> u64 {
+   let bit_width = inputs[0].len();
+
+   let mut more_list: Vec<Vec<char>> = inputs.to_vec();
+   let mut fewer_list = more_list.clone();
+
+   let mut with_ones = Vec::<Vec<char>>::new();
+   let mut with_zeros = with_ones.clone();
+   for i in 0..bit_width {
+      // more_list
+      if more_list.len() != 1 {
+         with_ones.clear();
+         with_zeros.clear();
+         for bits in more_list {
+            if bits[i] == '1' {
+               with_ones.push(bits.clone());
+            } else {
+               with_zeros.push(bits.clone());
+            }
+         }
+         if with_ones.len() >= with_zeros.len() {
+            more_list = with_ones.clone();
+         } else {
+            more_list = with_zeros.clone();
+         }
+      }
+
+      if fewer_list.len() != 1 {
+         // fewer_list
+         with_ones.clear();
+         with_zeros.clear();
+         for bits in fewer_list {
+            if bits[i] == '1' {
+               with_ones.push(bits.clone());
+            } else {
+               with_zeros.push(bits.clone());
+            }
+         }
+         if with_ones.len() >= with_zeros.len() {
+            fewer_list = with_zeros.clone();
+         } else {
+            fewer_list = with_ones.clone();
+         }
+      }
+   }
+   assert_eq!(more_list.len(), 1);
+   assert_eq!(fewer_list.len(), 1);
+
+   let o2_gen_rating = u64::from_str_radix(more_list[0].iter().collect::<String>().as_str(), 2).unwrap();
+   let co2_scrubber_rating = u64::from_str_radix(fewer_list[0].iter().collect::<String>().as_str(), 2).unwrap();
+   o2_gen_rating * co2_scrubber_rating
+}
+
+// -
+
+#[test]
+fn test_example() {
+   let example = fixture!("example.txt");
+   assert_eq!(Day3::solve1(example), 198);
+   assert_eq!(Day3::solve2(example), 230);
+   assert!(Day3::load("").is_err());
+}
@@ -0,0 +1,147 @@
+use aoc_common::{Result, Solution, Source};
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+   type Input = (Vec<usize>, Vec<BingoBoard>);
+   type Answer1 = usize;
+   type Answer2 = usize;
+   const DAY: u32 = 4;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse_inputs(input)
+   }
+   fn part1((mark_list, bbs): &Self::Input) -> usize {
+      part1(mark_list, bbs.clone())
+   }
+   fn part2((mark_list, bbs): &Self::Input) -> usize {
+      part2(mark_list, bbs.clone())
+   }
+}
+
+// -
+
+#[derive(Clone)]
+struct BingoNumber {
+   val: usize,
+   marked: bool,
+}
+
+#[derive(Clone)]
+pub struct BingoBoard {
+   rows: Vec<Vec<BingoNumber>>,
+}
+
+impl BingoBoard {
+   fn mark(&mut self, val: usize) -> Option<(usize,usize)> {
+      for (y, row) in self.rows.iter_mut().enumerate() {
+         for (x, cur) in row.iter_mut().enumerate() {
+            if cur.val == val {
+               cur.marked = true;
+               return Some((x,y));
+            }
+         }
+      }
+      None
+   }
+   fn is_bingo(&self, x: usize, y: usize) -> bool {
+      self.rows[y].iter().map(|bn| bn.marked as usize).sum::<usize>() == 5 ||
+             self.rows.iter().map(|row| row[x].marked as usize).sum::<usize>() == 5
+   }
+   fn unmarked_sum(&self) -> usize {
+      self.rows.iter().flatten().map(|bn| (!bn.marked) as usize * bn.val).sum()
+   }
+
+   fn try_mark(&mut self, val: usize) -> Option<usize> {
+      match self.mark(val) {
+         Some((x,y)) => {
+            if self.is_bingo(x,y) {
+               return Some(self.unmarked_sum() * val);
+            }
+            None
+         },
+         _ => None,
+      }
+   }
+}
+
+fn parse_inputs(input: &str) -> Result<(Vec<usize>, Vec<BingoBoard>)> {
+   let src = Source::new(input);
+   let mut inputs = input.split('\n')
+                     .filter(|x| !x.is_empty());
+   let mark_list = inputs.next()
+                         .ok_or_else(|| src.error(input, "numbers to draw"))?
+                         .split(',')
+                         .map(|s| src.parse(s, "a number"))
+                         .collect::<Result<_>>()?;
+
+   let mut bbs = Vec::<BingoBoard>::new();
+   let mut rows = Vec::<Vec<BingoNumber>>::new();
+   for line in inputs {
+      let row: Vec<BingoNumber> = line.split_whitespace()
+                                      .map(|s| Ok(BingoNumber{
+                                          val: src.parse(s, "a number")?,
+                                          marked: false,
+                                       }))
+                                      .collect::<Result<_>>()?;
+      if row.len() != 5 {
+         return Err(src.error(line, "a row of 5 numbers"));
+      }
+      rows.push(row);
+
+      if rows.len() == 5 {
+         bbs.push(BingoBoard {rows});
+         rows = Vec::<Vec<BingoNumber>>::new();
+      }
+   }
+   if !rows.is_empty() {
+      return Err(src.error(&input[input.len()..], "5 rows per board"));
+   }
+   Ok((mark_list, bbs))
+}
+
+// "What will your final score be if you choose that board?"
+fn part1(mark_list: &[usize], mut bbs: Vec<BingoBoard>) -> usize {
+   for &new_mark in mark_list {
+      for bb in bbs.iter_mut() {
+         if let Some(score) = bb.try_mark(new_mark) {
+            return score;
+         }
+      }
+   }
+   panic!("Bingo not found!");
+}
+
+// "Figure out which board will win last. Once it wins, what would its
+//  final score be?"
+fn part2(mark_list: &[usize], mut bbs: Vec<BingoBoard>) -> usize {
+   for &new_mark in mark_list {
+      let mut winners = Vec::<usize>::new();
+      let last_one = bbs.len() == 1;
+      for (i,bb) in bbs.iter_mut().enumerate() {
+         if let Some(score) = bb.try_mark(new_mark) {
+            if last_one {
+               return score;
+            }
+            winners.push(i);
+            //break;
+         }
+      }
+      winners.sort_unstable();
+      while let Some(i) = winners.pop() {
+         bbs.remove(i);
+      }
+   }
+   panic!("Bingo not found!");
+}
+
+// -
+
+#[test]
+fn test_example() {
+   let example = fixture!("example.txt");
+   assert_eq!(Day4::solve1(example), 4512);
+   assert_eq!(Day4::solve2(example), 1924);
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use aoc_common::{Result, Solution, Source};
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+   type Input = Vec<Line>;
+   type Answer1 = usize;
+   type Answer2 = usize;
+   const DAY: u32 = 5;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse_inputs(input)
+   }
+   // "Consider only horizontal and vertical lines. At how many points do at
+   //  least two lines overlap?"
+   fn part1(lines: &Self::Input) -> usize {
+      solve(lines, false)
+   }
+   // "Consider all of the lines. At how many points do at least two lines
+   //  overlap?"
+   fn part2(lines: &Self::Input) -> usize {
+      solve(lines, true)
+   }
+}
+
+// -
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+struct Point {
+   x: isize,
+   y: isize,
+}
+
+impl Point {
+   fn parse(src: &Source, s: &str) -> Result<Point> {
+      let (x,y) = src.split_once(s, ",")?;
+      Ok(Point{x:src.parse(x, "an x coordinate")?,
+               y:src.parse(y, "a y coordinate")?})
+   }
+}
+
+pub struct Line {
+   a: Point,
+   b: Point,
+}
+
+impl Line {
+   fn parse(src: &Source, s: &str) -> Result<Line> {
+      let (a, b) = src.split_once(s, " -> ")?;
+      Ok(Line{a:Point::parse(src, a)?,
+              b:Point::parse(src, b)?})
+   }
+}
+
+fn sign(v: isize) -> isize {
+   if v == 0 { 0 }
+   else if v < 0 { -1 }
+   else { 1 }
+}
+
+fn parse_inputs(input: &str) -> Result<Vec<Line>> {
+   let src = Source::new(input);
+   src.not_empty("a line")?;
+   input.split('\n')
+      .filter(|x| !x.is_empty())
+      .map(|x| Line::parse(&src, x))
+      .collect()
+}
+
+fn solve(lines: &[Line], with_diagonals: bool) -> usize {
+   let mut map = HashMap::new();
+   let mut dangers = 0;
+   for line in lines {
+      let mut p = line.a.clone();
+      let dx = sign(line.b.x - line.a.x);
+      let dy = sign(line.b.y - line.a.y);
+      if !with_diagonals && dx != 0 && dy != 0 {
+         continue;
+      }
+      loop {
+         {
+            let e = map.entry(p.clone()).or_insert(0);
+            *e += 1;
+            if *e == 2 {
+               dangers += 1;
+            }
+         }
+
+         if p == line.b { break; }
+         if p.x != line.b.x { p.x += dx; }
+         if p.y != line.b.y { p.y += dy; }
+      }
+   }
+   dangers
+}
+
+#[test]
+fn example() {
+   let example_input = fixture!("example.txt");
+   assert_eq!(Day5::solve1(example_input), 5);
+   assert_eq!(Day5::solve2(example_input), 12);
+   assert!(Day5::load("").is_err());
+}
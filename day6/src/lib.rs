@@ -0,0 +1,78 @@
+use memoise::memoise;
+use aoc_common::{Result, Solution, Source};
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+   type Input = Vec<usize>;
+   type Answer1 = usize;
+   type Answer2 = usize;
+   const DAY: u32 = 6;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse_inputs(input)
+   }
+   // "How many lanternfish would there be after 80 days?"
+   fn part1(fish_list: &Self::Input) -> usize {
+      solve(fish_list, 80)
+   }
+   // "How many lanternfish would there be after 256 days?"
+   fn part2(fish_list: &Self::Input) -> usize {
+      solve(fish_list, 256)
+   }
+}
+
+// -
+
+fn parse_inputs(input: &str) -> Result<Vec<usize>> {
+   let src = Source::new(input);
+   let mut inputs = input.split('\n')
+                     .filter(|x| !x.is_empty());
+   inputs.next()
+      .ok_or_else(|| src.error(input, "a list of timers"))?
+      .split(',')
+      .map(|x| {
+         let timer = src.parse(x, "a timer")?;
+         // fish_after_n is only memoised this far.
+         if timer > 8 {
+            return Err(src.error(x, "a timer from 0 to 8"));
+         }
+         Ok(timer)
+      })
+      .collect()
+}
+
+fn solve(fish_list: &[usize], days: usize) -> usize {
+   fish_list.iter().map(|&f| fish_after_n(f, days)).sum()
+}
+
+#[memoise(timer <= 8, days <= 256)]
+fn fish_after_n(timer: usize, days: usize) -> usize {
+   if timer >= days { return 1; }
+   let days_left_at_first_spawn = days - timer - 1;
+   return fish_after_n(6, days_left_at_first_spawn) +
+          fish_after_n(8, days_left_at_first_spawn);
+}
+
+#[test]
+fn test_fish_after_n() {
+   assert_eq!(fish_after_n(1, 1), 1);
+   assert_eq!(fish_after_n(0, 1), 2);
+   assert_eq!(fish_after_n(0, 2), 2);
+   assert_eq!(fish_after_n(0, 3), 2);
+   assert_eq!(fish_after_n(0, 4), 2);
+   assert_eq!(fish_after_n(0, 5), 2);
+   assert_eq!(fish_after_n(0, 6), 2);
+   assert_eq!(fish_after_n(0, 7), 2);
+   assert_eq!(fish_after_n(0, 8), 3);
+}
+#[test]
+fn example() {
+   let example_input = fixture!("example.txt");
+   let fish_list = Day6::parse(example_input).unwrap();
+   assert_eq!(solve(&fish_list, 18), 26);
+   assert_eq!(Day6::part1(&fish_list), 5934);
+   assert_eq!(Day6::part2(&fish_list), 26984457539);
+}
@@ -0,0 +1,94 @@
+use aoc_common::{Result, Solution, Source};
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+   type Input = Vec<isize>;
+   type Answer1 = isize;
+   type Answer2 = isize;
+   const DAY: u32 = 7;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse_inputs(input)
+   }
+   // "How much fuel must they spend to align to that position?"
+   fn part1(inputs: &Self::Input) -> isize {
+      solve(inputs, linear_move_cost)
+   }
+   // Same, but "each change of 1 step in horizontal position costs 1 more
+   // unit of fuel than the last".
+   fn part2(inputs: &Self::Input) -> isize {
+      solve(inputs, move_cost)
+   }
+}
+
+// -
+
+fn parse_inputs(input: &str) -> Result<Vec<isize>> {
+   let src = Source::new(input);
+   input.trim().split(',')
+                   .map(|x| src.parse(x, "a position"))
+                   .collect()
+}
+
+type MoveCostFn = fn(isize, isize) -> isize;
+
+fn linear_move_cost(a: isize, b: isize) -> isize {
+   (a - b).abs()
+}
+
+fn move_cost(a: isize, b: isize) -> isize {
+   let dist = (a - b).abs();
+   // These are triangle numbers
+   dist * (dist+1) / 2
+}
+
+#[test]
+fn test_move_cost() {
+   assert_eq!(move_cost(0,0), 0);
+   assert_eq!(move_cost(0,1), 1);
+   assert_eq!(move_cost(0,2), 3);
+   assert_eq!(move_cost(0,3), 6);
+}
+
+fn cost_align_at(inputs: &[isize], x_target: isize, move_cost: MoveCostFn) -> isize {
+   inputs.iter().map(|x| move_cost(*x, x_target)).sum()
+}
+
+fn solve(inputs: &[isize], move_cost: MoveCostFn) -> isize {
+   fn min_cost(inputs: &[isize], begin: isize, end: isize, move_cost: MoveCostFn) -> isize {
+      if end - begin <= 4 {
+         (begin..end).map(|x| cost_align_at(inputs, x, move_cost)).min().unwrap()
+      } else {
+         let x_first_right = (begin + end) / 2; // (0+4)/2 = 2
+         let x_last_left = x_first_right - 1;
+
+         let cost_last_left = cost_align_at(inputs, x_last_left, move_cost);
+         let cost_first_right = cost_align_at(inputs, x_first_right, move_cost);
+
+         if cost_last_left < cost_first_right {
+            min_cost(inputs, begin, x_first_right, move_cost)
+         } else {
+            min_cost(inputs, x_first_right, end, move_cost)
+         }
+      }
+   }
+   min_cost(inputs, 0, inputs.len() as isize, move_cost)
+}
+
+// -
+
+#[test]
+fn test_example() {
+   let example_input = fixture!("example.txt");
+   let inputs = parse_inputs(example_input).unwrap();
+   assert_eq!(cost_align_at(&inputs, 1, linear_move_cost), 41);
+   assert_eq!(cost_align_at(&inputs, 3, linear_move_cost), 39);
+   assert_eq!(cost_align_at(&inputs, 10, linear_move_cost), 71);
+   assert_eq!(Day7::part1(&inputs), 37);
+
+   assert_eq!(cost_align_at(&inputs, 2, move_cost), 206);
+   assert_eq!(Day7::part2(&inputs), 168);
+}
@@ -1,10 +1,31 @@
-use std::fs::File;
-use std::io::Read;
-use std::path::Path;
 use std::collections::HashSet;
 use std::collections::HashMap;
+use aoc_common::{Result, Solution, Source};
+#[cfg(test)]
+use aoc_common::fixture;
 
-struct Case {
+pub struct Day8;
+
+impl Solution for Day8 {
+   type Input = Vec<Case>;
+   type Answer1 = isize;
+   type Answer2 = isize;
+   const DAY: u32 = 8;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse_inputs(input)
+   }
+   fn part1(cases: &Self::Input) -> isize {
+      part1(cases)
+   }
+   fn part2(cases: &Self::Input) -> isize {
+      part2(cases)
+   }
+}
+
+// -
+
+pub struct Case {
    test_pats: Vec<HashSet<char>>,
    out_pats: Vec<HashSet<char>>,
 }
@@ -15,8 +36,8 @@ impl Case {
       // 1, 7, 4, and 8 have unique num segments.
       let mut pats_by_len = HashMap::< usize, Vec<&HashSet<char>> >::new();
       for p in self.test_pats.iter() {
-         let e = pats_by_len.entry(p.len()).or_insert(Vec::new());
-         e.push(&p);
+         let e = pats_by_len.entry(p.len()).or_default();
+         e.push(p);
       }
       // 2: 1
       // 3: 7
@@ -68,7 +89,7 @@ impl Case {
       assert_eq!(pat_by_val.len(), 10);
 
       fn set_to_str(set: &HashSet<char>) -> String {
-         let mut chars: Vec<char> = set.into_iter().cloned().collect();
+         let mut chars: Vec<char> = set.iter().cloned().collect();
          chars.sort();
          chars.into_iter().collect()
       }
@@ -80,76 +101,62 @@ impl Case {
          .map(|p| {
             let s = set_to_str(p);
             val_by_pat[&s]
-         }).fold(0 as isize, |prev,next| 10*prev + next)
+         }).fold(0_isize, |prev,next| 10*prev + next)
    }
 }
 
-fn parse_inputs(input: &str) -> Vec<Case> {
-   fn to_pat_sets(s: &str) -> Vec<HashSet<char>> {
-      s.split_whitespace()
-       .map(|s| s.chars().collect())
-       .collect()
-   }
+fn parse_inputs(input: &str) -> Result<Vec<Case>> {
+   let src = Source::new(input);
+   let to_pat_sets = |s: &str, count: usize| {
+      let pats = s.split_whitespace()
+       .map(|s| {
+          if let Some(bad) = s.find(|c| !('a'..='g').contains(&c)) {
+             return Err(src.error(&s[bad..bad+1], "a segment from a to g"));
+          }
+          Ok(s.chars().collect())
+       })
+       .collect::<Result<Vec<HashSet<char>>>>()?;
+      if pats.len() != count {
+         return Err(src.error(s, &format!("{} patterns", count)));
+      }
+      Ok(pats)
+   };
    input
       .trim()
       .split("\n")
       .map(|x| {
-         let (test_pats, out_pats) = x.split_once(" | ").unwrap();
-         Case{test_pats: to_pat_sets(test_pats),
-              out_pats: to_pat_sets(out_pats)}
+         let (test_pats, out_pats) = src.split_once(x, " | ")?;
+         Ok(Case{test_pats: to_pat_sets(test_pats, 10)?,
+                 out_pats: to_pat_sets(out_pats, 4)?})
       }).collect()
 }
 
+// "In the output values, how many times do digits 1, 4, 7, or 8 appear?"
+fn part1(cases: &[Case]) -> isize {
+   const UNIQUE_SEGMENT_COUNTS: [usize;4] = [2, 3, 4, 7]; // 1, 7, 4, 8 respectively
+   cases.iter().map(|c| {
+      c.out_pats.iter().map(|p| {
+         UNIQUE_SEGMENT_COUNTS.contains(&p.len()) as isize
+      }).sum::<isize>()
+   }).sum()
+}
+
 // "For each entry, determine all of the wire/segment connections and
 //  decode the four-digit output values. What do you get if you add up
 //  all of the output values?"
-fn solve(input: &str) -> isize {
-   let cases = parse_inputs(input);
+fn part2(cases: &[Case]) -> isize {
    cases.iter().map(|c| c.solve()).sum()
 }
 
+// -
 
 #[test]
 fn test_example() {
-   let small_input = "\
-acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab |
-cdfeb fcadb cdfeb cdbaf
-".replace("|\n", "| ");
-   assert_eq!(solve(&small_input), 5353);
-
-   let big_input = "\
-be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb |
-fdgacbe cefdb cefbgd gcbe
-edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec |
-fcgedb cgb dgebacf gc
-fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef |
-cg cg fdcagb cbg
-fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega |
-efabcd cedba gadfec cb
-aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga |
-gecf egdcabf bgf bfgea
-fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf |
-gebdcfa ecba ca fadegcb
-dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf |
-cefg dcbef fcge gbcadfe
-bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd |
-ed bcgafe cdgba cbgef
-egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg |
-gbdfcae bgc cg cgb
-gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc |
-fgae cfgab fg bagce
-".replace("|\n", "| ");
-   assert_eq!(solve(&big_input), 61229);
-}
-
-fn main() {
-   let path = Path::new("day8-1/input.txt");
-   let mut file = match File::open(&path) {
-      Err(why) => panic!("File::open({}) -> Err({})", path.display(), why),
-      Ok(file) => file,
-   };
-   let mut input = String::new();
-   file.read_to_string(&mut input).unwrap();
+   let small_input = fixture!("example-small.txt");
+   assert_eq!(Day8::solve1(small_input), 0);
+   assert_eq!(Day8::solve2(small_input), 5353);
 
-   println!("solve(input) -> {}", solve(&input));
+   let big_input = fixture!("example.txt");
+   assert_eq!(Day8::solve1(big_input), 26);
+   assert_eq!(Day8::solve2(big_input), 61229);
 }
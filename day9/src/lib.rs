@@ -0,0 +1,81 @@
+use std::collections::HashSet;
+use aoc_common::{Grid, Point, Result, Solution};
+#[cfg(test)]
+use aoc_common::fixture;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+   type Input = HeightMap;
+   type Answer1 = isize;
+   type Answer2 = isize;
+   const DAY: u32 = 9;
+
+   fn parse(input: &str) -> Result<Self::Input> {
+      parse_inputs(input)
+   }
+   fn part1(hm: &Self::Input) -> isize {
+      part1(hm)
+   }
+   fn part2(hm: &Self::Input) -> isize {
+      part2(hm)
+   }
+}
+
+// -
+
+pub type HeightMap = Grid<isize>;
+
+// Off-grid neighbors don't count, as if the map were bordered by peaks.
+fn low_points(hm: &HeightMap) -> Vec<Point> {
+   hm.points().filter(|p| {
+      let v = hm.at(p);
+      hm.nearest4(p).all(|n| v < hm.at(&n))
+   }).collect()
+}
+
+fn parse_inputs(input: &str) -> Result<HeightMap> {
+   Grid::from_digits(input)
+}
+
+// "Find all of the low points on your heightmap. What is the sum of the
+//  risk levels of all low points on your heightmap?"
+fn part1(hm: &HeightMap) -> isize {
+   low_points(hm).into_iter().map(|p| 1+hm.at(&p)).sum()
+}
+
+// "What do you get if you multiply together the sizes of the three
+//  largest basins?"
+fn part2(hm: &HeightMap) -> isize {
+   let mut basin_sizes: Vec<isize> = low_points(hm).into_iter()
+      .map(|initial_p| {
+         let mut basin_points = HashSet::new();
+         let mut edge_points = Vec::new();
+         basin_points.insert(initial_p);
+         edge_points.push(initial_p);
+
+         while let Some(p) = edge_points.pop() {
+            //println!("popped {},{}", p.x, p.y);
+            hm.nearest4(&p).for_each(|p| {
+               //println!("   trying {},{}", p.x, p.y);
+               if basin_points.contains(&p) || *hm.at(&p) >= 9 { return; }
+               basin_points.insert(p);
+               edge_points.push(p);
+            });
+         }
+         //edge_points
+         basin_points.len() as isize
+      }).collect();
+   basin_sizes.sort_unstable_by_key(|v| -v);
+   basin_sizes[0..3].iter().product()
+}
+
+// -
+
+#[test]
+fn test_example() {
+   let input = fixture!("example.txt");
+   assert_eq!(Day9::solve1(input), 15);
+   assert_eq!(Day9::solve2(input), 1134);
+   assert!(Day9::load("").is_err());
+}